@@ -0,0 +1,39 @@
+//! Compare filling a buffer with [Wdg::fill] against assigning [Wdg::f32] to
+//! each element.
+//!
+//! Run with `cargo bench --bench fill`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use weird_data::Wdg;
+
+const BUF_LEN: usize = 16 * 1024;
+const ITERATIONS: u32 = 1000;
+
+fn time(name: &str, mut f: impl FnMut(&mut [f32])) -> Duration {
+    let mut buf = vec![0.0f32; BUF_LEN];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f(black_box(&mut buf));
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    println!("{name}: {elapsed:?} per 16 Ki f32 buffer");
+    elapsed
+}
+
+fn main() {
+    let mut gen = Wdg::with_seed(0x5f_e2_0b_97_c4_38_ad_61);
+    let bulk = time("fill", |buf| {
+        gen.fill(buf);
+    });
+
+    let mut gen = Wdg::with_seed(0x5f_e2_0b_97_c4_38_ad_61);
+    let single = time("f32 loop", |buf| {
+        for x in buf.iter_mut() {
+            *x = gen.f32();
+        }
+    });
+
+    println!("ratio: {:.2}x", single.as_secs_f64() / bulk.as_secs_f64());
+}
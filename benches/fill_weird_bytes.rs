@@ -0,0 +1,39 @@
+//! Compare filling a buffer with [Wdg::fill_weird_bytes] against calling
+//! [Wdg::u8] for each byte.
+//!
+//! Run with `cargo bench --bench fill_weird_bytes`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use weird_data::Wdg;
+
+const BUF_LEN: usize = 64 * 1024;
+const ITERATIONS: u32 = 1000;
+
+fn time(name: &str, mut f: impl FnMut(&mut [u8])) -> Duration {
+    let mut buf = vec![0u8; BUF_LEN];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f(black_box(&mut buf));
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    println!("{name}: {elapsed:?} per 64 KiB buffer");
+    elapsed
+}
+
+fn main() {
+    let mut gen = Wdg::with_seed(0x7d_3e_a1_c4_52_f8_09_6b);
+    let bulk = time("fill_weird_bytes", |buf| {
+        gen.fill_weird_bytes(buf);
+    });
+
+    let mut gen = Wdg::with_seed(0x7d_3e_a1_c4_52_f8_09_6b);
+    let single = time("u8 loop", |buf| {
+        for byte in buf.iter_mut() {
+            *byte = gen.u8();
+        }
+    });
+
+    println!("speedup: {:.1}x", single.as_secs_f64() / bulk.as_secs_f64());
+}
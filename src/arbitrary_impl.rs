@@ -0,0 +1,25 @@
+//! Integration with the [arbitrary] crate.
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Create a new Wdg seeded from the fuzzer's byte stream.
+    ///
+    /// This consumes 8 bytes of the input to seed the generator, so the same
+    /// fuzzer input always produces the same weird values. If fewer bytes are
+    /// left in the input, the missing ones are treated as zeros.
+    ///
+    /// ```
+    /// use arbitrary::Unstructured;
+    /// use weird_data::Wdg;
+    ///
+    /// let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let mut gen = Wdg::from_unstructured(&mut u).unwrap();
+    /// let num = gen.f32();
+    /// ```
+    pub fn from_unstructured(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(Wdg::with_seed(u64::arbitrary(u)?))
+    }
+}
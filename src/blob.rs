@@ -0,0 +1,113 @@
+//! Generators of weird binary payloads.
+
+use alloc::vec::Vec;
+
+use crate::Wdg;
+
+/// Byte order of the integers and floats in [Wdg::weird_blob].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Least significant byte first
+    Little,
+    /// Most significant byte first
+    Big,
+}
+
+macro_rules! push_bytes {
+    ($blob:expr, $endian:expr, $x:expr) => {
+        match $endian {
+            Endian::Little => $blob.extend_from_slice(&$x.to_le_bytes()),
+            Endian::Big => $blob.extend_from_slice(&$x.to_be_bytes()),
+        }
+    };
+}
+
+impl Wdg {
+    /// Generate a random byte buffer that looks almost like a sequence of
+    /// binary records.
+    ///
+    /// The buffer is a concatenation of weird integers of all sizes, the bit
+    /// patterns of weird floats, including `NAN`, and length-prefixed chunks
+    /// of bytes where the `u32` length prefix often lies about the length of
+    /// the chunk. The integers, floats and length prefixes are encoded with
+    /// the given byte order. The buffer is at most `max_len` bytes long, and
+    /// the last record is often cut short.
+    pub fn weird_blob(&mut self, max_len: usize, endian: Endian) -> Vec<u8> {
+        let len = match self.0.u8(0..4) {
+            0 => 0,
+            1 => max_len.min(1),
+            2 => max_len,
+            3 => self.0.usize(0..=max_len),
+            _ => unreachable!(),
+        };
+
+        let mut blob = Vec::with_capacity(len + 8);
+        while blob.len() < len {
+            match self.0.u8(0..7) {
+                0 => blob.push(self.u8()),
+                1 => push_bytes!(blob, endian, self.u16()),
+                2 => push_bytes!(blob, endian, self.i32()),
+                3 => push_bytes!(blob, endian, self.u64()),
+                4 => push_bytes!(blob, endian, self.f32().to_bits()),
+                5 => push_bytes!(blob, endian, self.f64().to_bits()),
+                6 => {
+                    let chunk_len = self.0.usize(0..=16);
+                    let prefix = match self.0.u8(0..4) {
+                        0 => chunk_len as u32,
+                        1 => chunk_len as u32 + 1,
+                        2 => (chunk_len as u32).wrapping_sub(1),
+                        3 => self.u32(),
+                        _ => unreachable!(),
+                    };
+                    push_bytes!(blob, endian, prefix);
+                    let start = blob.len();
+                    blob.resize(start + chunk_len, 0);
+                    self.fill_weird_bytes(&mut blob[start..]);
+                }
+                _ => unreachable!(),
+            }
+        }
+        blob.truncate(len);
+        blob
+    }
+
+    /// Generate a byte buffer of length `len` that is mostly the `fill` byte,
+    /// with a few positions flipped to weird bytes, like `0xFF` and the
+    /// other boundary bytes of [Wdg::u8].
+    ///
+    /// This makes "nearly valid" payloads, like zero padding with occasional
+    /// garbage. The density of the flips is random, often a single flip,
+    /// sometimes many, see [Wdg::bytes_with_pattern_density] to control it.
+    pub fn bytes_with_pattern(&mut self, len: usize, fill: u8) -> Vec<u8> {
+        let density = match self.0.u8(0..4) {
+            0 => 1.0 / len.max(1) as f64,
+            1 => 0.01,
+            2 => 0.1,
+            3 => 0.5,
+            _ => unreachable!(),
+        };
+        self.bytes_with_pattern_density(len, fill, density)
+    }
+
+    /// Generate a byte buffer of length `len` that is made of the `fill`
+    /// byte, where every position is flipped to a weird byte with
+    /// probability `density`.
+    ///
+    /// A flipped byte is generated with [Wdg::u8], and is always different
+    /// from `fill`. The density is clamped like the probability of
+    /// [Wdg::weird_bool].
+    pub fn bytes_with_pattern_density(&mut self, len: usize, fill: u8, density: f64) -> Vec<u8> {
+        let mut bytes = alloc::vec![fill; len];
+        for byte in &mut bytes {
+            if self.weird_bool(density) {
+                *byte = loop {
+                    let flipped = self.u8();
+                    if flipped != fill {
+                        break flipped;
+                    }
+                };
+            }
+        }
+        bytes
+    }
+}
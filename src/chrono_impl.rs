@@ -0,0 +1,98 @@
+//! Integration with [chrono].
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::Wdg;
+
+/// Years that tend to break date handling code.
+const SPECIAL_YEARS: [i32; 10] = [0, -1, 1, 1900, 1970, 2000, 2038, 2100, 9999, 10000];
+
+/// Local times that do not exist or are ambiguous around daylight saving
+/// time transitions, in the US and in the EU.
+const DST_TRANSITIONS: [(i32, u32, u32, u32, u32); 4] = [
+    (2021, 3, 14, 2, 30),  // skipped in the US
+    (2021, 11, 7, 1, 30),  // repeated in the US
+    (2021, 3, 28, 2, 30),  // skipped in the EU
+    (2021, 10, 31, 2, 30), // repeated in the EU
+];
+
+impl Wdg {
+    /// Generate a random `NaiveDate`, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// The special values are `NaiveDate::MIN` and `NaiveDate::MAX`, leap
+    /// days (February 29), the first and last days of the year, the days
+    /// around the end of February, and years like 0, 1970, 2000 and 10000.
+    pub fn weird_naive_date(&mut self) -> NaiveDate {
+        let (min_year, max_year) = (NaiveDate::MIN.year(), NaiveDate::MAX.year());
+        let year = if self.0.bool() {
+            SPECIAL_YEARS[self.0.usize(0..SPECIAL_YEARS.len())]
+        } else {
+            self.0.i32(min_year..=max_year)
+        };
+
+        match self.0.u8(0..7) {
+            0 => NaiveDate::MIN,
+            1 => NaiveDate::MAX,
+            2 => {
+                // the closest leap year that is still in range
+                let mut leap_year = year - year.rem_euclid(4);
+                while leap_year % 100 == 0 && leap_year % 400 != 0 || leap_year < min_year {
+                    leap_year += 4;
+                }
+                NaiveDate::from_ymd_opt(leap_year, 2, 29).unwrap()
+            }
+            3 => NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(NaiveDate::MIN),
+            4 => NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(NaiveDate::MAX),
+            5 => {
+                let day = if self.0.bool() {
+                    NaiveDate::from_ymd_opt(year, 2, 28)
+                } else {
+                    NaiveDate::from_ymd_opt(year, 3, 1)
+                };
+                day.unwrap_or(NaiveDate::MAX)
+            }
+            6 => {
+                let first = NaiveDate::MIN.num_days_from_ce();
+                let last = NaiveDate::MAX.num_days_from_ce();
+                NaiveDate::from_num_days_from_ce_opt(self.0.i32(first..=last)).unwrap()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `NaiveDateTime`, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// The dates are generated with [Wdg::weird_naive_date], and the times
+    /// are often midnight, the last nanosecond of the day, or a leap second.
+    /// Local times that are skipped or repeated by daylight saving time
+    /// transitions are also common, as are `NaiveDateTime::MIN` and
+    /// `NaiveDateTime::MAX`.
+    pub fn weird_datetime(&mut self) -> NaiveDateTime {
+        let date = self.weird_naive_date();
+        match self.0.u8(0..7) {
+            0 => date.and_time(NaiveTime::MIN),
+            1 => date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+            // leap second
+            2 => date
+                .and_hms_nano_opt(23, 59, 59, 1_000_000_000 + self.0.u32(0..1_000_000_000))
+                .unwrap(),
+            3 => {
+                let (y, m, d, h, min) = DST_TRANSITIONS[self.0.usize(0..DST_TRANSITIONS.len())];
+                NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(h, min, 0)
+                    .unwrap()
+            }
+            4 => NaiveDateTime::MIN,
+            5 => NaiveDateTime::MAX,
+            6 => {
+                let secs = self.0.u32(0..86_400);
+                let nanos = self.0.u32(0..1_000_000_000);
+                date.and_time(NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos).unwrap())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
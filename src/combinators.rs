@@ -0,0 +1,211 @@
+//! Generators composing other generators.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Generate a random `Option`, such that `None` is much more common than
+    /// normal.
+    ///
+    /// `None` is generated 40% of the time, otherwise the value is generated
+    /// with `f`.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let x: Option<f32> = gen.weird_option(|g| g.f32());
+    /// ```
+    pub fn weird_option<T>(&mut self, f: impl FnOnce(&mut Wdg) -> T) -> Option<T> {
+        match self.0.u8(0..5) {
+            0..=1 => None,
+            _ => Some(f(self)),
+        }
+    }
+
+    /// Generate a random `Result`, such that `Err` is much more common than
+    /// normal.
+    ///
+    /// `Err` is generated 60% of the time with `err`, otherwise `Ok` is
+    /// generated with `ok`.
+    pub fn weird_result<T, E>(
+        &mut self,
+        ok: impl FnOnce(&mut Wdg) -> T,
+        err: impl FnOnce(&mut Wdg) -> E,
+    ) -> Result<T, E> {
+        match self.0.u8(0..5) {
+            0..=2 => Err(err(self)),
+            _ => Ok(ok(self)),
+        }
+    }
+
+    /// Generate a random enum discriminant, for an enum with `count`
+    /// variants, such that invalid discriminants are much more common than
+    /// normal.
+    ///
+    /// The values are biased towards 0, `count - 1`, `count` (the first
+    /// invalid discriminant) and `u32::MAX`. About half of the values are
+    /// valid discriminants. If `count` is 0, every value is invalid, so this
+    /// is the same as [Wdg::u32].
+    pub fn weird_enum_index(&mut self, count: u32) -> u32 {
+        if count == 0 {
+            return self.u32();
+        }
+        match self.0.u8(0..6) {
+            0 => 0,
+            1 => count - 1,
+            2 => count,
+            3 => u32::MAX,
+            4 => self.0.u32(0..count),
+            5 => self.0.u32(count..=u32::MAX),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random index into a collection of length `len`, such that
+    /// the indices at and past the bounds are much more common than normal.
+    ///
+    /// The values are biased towards 0, `len - 1` (the valid extremes),
+    /// `len` (the first out of bounds index) and `usize::MAX`. About half of
+    /// the values are valid indices. If `len` is 0, every index is out of
+    /// bounds, so this is the same as [Wdg::usize].
+    ///
+    /// Unlike [Wdg::weird_len], this deliberately generates out of range
+    /// values, to exercise the code paths that panic or return `None`.
+    pub fn weird_index_into(&mut self, len: usize) -> usize {
+        if len == 0 {
+            return self.usize();
+        }
+        match self.0.u8(0..6) {
+            0 => 0,
+            1 => len - 1,
+            2 => len,
+            3 => usize::MAX,
+            4 => self.0.usize(0..len),
+            5 => self.0.usize(len..=usize::MAX),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random length, such that problematic lengths are much more
+    /// common than normal.
+    ///
+    /// The length is at most `max`, and it is biased towards 0, 1, 2,
+    /// `max - 1` and `max`, the boundary sizes that tend to break capacity,
+    /// reallocation and loop bound logic. If `max` is 0, this always returns
+    /// 0.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let len = gen.weird_len(64);
+    /// assert!(len <= 64);
+    /// assert_eq!(gen.weird_len(0), 0);
+    /// ```
+    pub fn weird_len(&mut self, max: usize) -> usize {
+        match self.0.u8(0..7) {
+            0 => 0,
+            1 => max.min(1),
+            2 => max.min(2),
+            3 => max.saturating_sub(1),
+            // usize::MAX, capped at max
+            4 | 5 => max,
+            6 => self.0.usize(0..=max),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `Vec`, such that problematic lengths are much more
+    /// common than normal.
+    ///
+    /// The length is generated with [Wdg::weird_len], so it is at most
+    /// `max_len`, and it is biased towards 0, 1, 2, `max_len - 1` and
+    /// `max_len`, the boundary sizes that tend to break capacity and
+    /// reallocation logic. Every item is generated with `f`.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let v: Vec<u8> = gen.weird_vec(16, Wdg::u8);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn weird_vec<T>(&mut self, max_len: usize, mut f: impl FnMut(&mut Wdg) -> T) -> Vec<T> {
+        let len = self.weird_len(max_len);
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    /// Generate `n` values, each from either `a` or `b`, interleaving the two
+    /// sources unpredictably.
+    ///
+    /// Half of the time, the source of every value is picked independently,
+    /// so the sources alternate often. The rest of the time, the values come
+    /// in bursts: long runs from one source, followed by long runs from the
+    /// other. This models mixed input timelines, for instance for fuzzing a
+    /// state machine fed by two inputs.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let v: Vec<f32> = gen.interleave(Wdg::f32, |_| 1.0, 16);
+    /// assert_eq!(v.len(), 16);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn interleave<T>(
+        &mut self,
+        mut a: impl FnMut(&mut Wdg) -> T,
+        mut b: impl FnMut(&mut Wdg) -> T,
+        n: usize,
+    ) -> Vec<T> {
+        let bursty = self.0.bool();
+        let mut from_a = self.0.bool();
+        let mut values = Vec::with_capacity(n);
+        while values.len() < n {
+            let run = if bursty {
+                self.0.usize(1..=n - values.len())
+            } else {
+                1
+            };
+            for _ in 0..run {
+                values.push(if from_a { a(self) } else { b(self) });
+            }
+            from_a = if bursty { !from_a } else { self.0.bool() };
+        }
+        values
+    }
+
+    /// Generate `n` correlated booleans, for fuzzing flags that toggle a
+    /// state.
+    ///
+    /// Unlike independent [Wdg::bool] draws, the sequence has a deliberate
+    /// structure, picked at random:
+    /// - a single run of the same value, which is `true` half of the time
+    /// - a few long runs of alternating values
+    /// - a strictly alternating sequence, toggling at every step
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let v = gen.weird_bool_run(16);
+    /// assert_eq!(v.len(), 16);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn weird_bool_run(&mut self, n: usize) -> Vec<bool> {
+        let first = self.0.bool();
+        match self.0.u8(0..3) {
+            0 => alloc::vec![first; n],
+            1 => {
+                let mut values = Vec::with_capacity(n);
+                let mut value = first;
+                while values.len() < n {
+                    let run = self.0.usize(1..=n - values.len());
+                    values.resize(values.len() + run, value);
+                    value = !value;
+                }
+                values
+            }
+            2 => (0..n).map(|i| first ^ (i % 2 == 1)).collect(),
+            _ => unreachable!(),
+        }
+    }
+}
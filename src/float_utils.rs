@@ -1,9 +1,181 @@
-//! Internal module for extending float functionality
+//! Utilities for extending float functionality
+//!
+//! These are mostly useful for inspecting the values produced by the
+//! generators in this crate, for instance when writing assertions in tests.
 
+/// Compare two f32 for bit-exact equality.
+///
+/// Unlike `==`, this distinguishes `0.0` from `-0.0`, considers a `NAN` to be
+/// equal to itself, and considers `NAN` values with different payloads to be
+/// different.
 pub fn f32_exact_eq(lhs: f32, rhs: f32) -> bool {
     lhs.to_bits() == rhs.to_bits()
 }
 
+/// Compare two f64 for bit-exact equality.
+///
+/// Unlike `==`, this distinguishes `0.0` from `-0.0`, considers a `NAN` to be
+/// equal to itself, and considers `NAN` values with different payloads to be
+/// different.
 pub fn f64_exact_eq(lhs: f64, rhs: f64) -> bool {
     lhs.to_bits() == rhs.to_bits()
 }
+
+/// Check whether an f32 is a signaling `NAN`.
+///
+/// As per IEEE-754-2008, a `NAN` is signaling if the most significant bit of
+/// the mantissa is clear. The remaining mantissa bits must be nonzero,
+/// otherwise the value would be `INFINITY`.
+pub fn f32_is_signaling_nan(x: f32) -> bool {
+    x.is_nan() && x.to_bits() & (1 << 22) == 0
+}
+
+/// Check whether an f64 is a signaling `NAN`.
+///
+/// As per IEEE-754-2008, a `NAN` is signaling if the most significant bit of
+/// the mantissa is clear. The remaining mantissa bits must be nonzero,
+/// otherwise the value would be `INFINITY`.
+pub fn f64_is_signaling_nan(x: f64) -> bool {
+    x.is_nan() && x.to_bits() & (1 << 51) == 0
+}
+
+/// Check whether an f32 is a quiet `NAN`.
+///
+/// As per IEEE-754-2008, a `NAN` is quiet if the most significant bit of the
+/// mantissa is set.
+pub fn f32_is_quiet_nan(x: f32) -> bool {
+    x.is_nan() && x.to_bits() & (1 << 22) != 0
+}
+
+/// Check whether an f64 is a quiet `NAN`.
+///
+/// As per IEEE-754-2008, a `NAN` is quiet if the most significant bit of the
+/// mantissa is set.
+pub fn f64_is_quiet_nan(x: f64) -> bool {
+    x.is_nan() && x.to_bits() & (1 << 51) != 0
+}
+
+/// Step an f32 by the given number of ULPs, towards positive infinity if
+/// `ulps` is positive, or towards negative infinity if `ulps` is negative.
+///
+/// Stepping by one ULP gives the next representable value. Crossing zero is
+/// handled correctly, `0.0` and `-0.0` are considered the same value and
+/// stepping onto zero always gives `0.0`. The result saturates at the
+/// infinities, and a `NAN` is returned unchanged.
+pub fn f32_step_ulps(x: f32, ulps: i32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    // map the bits to a signed integer that increases monotonically with the
+    // value of the float, so that stepping is a plain addition
+    let bits = x.to_bits();
+    let ordered = if bits >> 31 == 1 {
+        -((bits & !(1 << 31)) as i64)
+    } else {
+        bits as i64
+    };
+
+    let max = f32::INFINITY.to_bits() as i64;
+    let stepped = (ordered + ulps as i64).clamp(-max, max);
+    let bits = if stepped < 0 {
+        (1 << 31) | (-stepped) as u32
+    } else {
+        stepped as u32
+    };
+    f32::from_bits(bits)
+}
+
+/// Step an f64 by the given number of ULPs, towards positive infinity if
+/// `ulps` is positive, or towards negative infinity if `ulps` is negative.
+///
+/// Stepping by one ULP gives the next representable value. Crossing zero is
+/// handled correctly, `0.0` and `-0.0` are considered the same value and
+/// stepping onto zero always gives `0.0`. The result saturates at the
+/// infinities, and a `NAN` is returned unchanged.
+pub fn f64_step_ulps(x: f64, ulps: i64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    // map the bits to a signed integer that increases monotonically with the
+    // value of the float, so that stepping is a plain addition
+    let bits = x.to_bits();
+    let ordered = if bits >> 63 == 1 {
+        -((bits & !(1 << 63)) as i128)
+    } else {
+        bits as i128
+    };
+
+    let max = f64::INFINITY.to_bits() as i128;
+    let stepped = (ordered + ulps as i128).clamp(-max, max);
+    let bits = if stepped < 0 {
+        (1 << 63) | (-stepped) as u64
+    } else {
+        stepped as u64
+    };
+    f64::from_bits(bits)
+}
+
+/// The class of a float, as returned by [classify_f32] and [classify_f64].
+///
+/// Unlike [core::num::FpCategory], this tells apart the signs of zeros and
+/// infinities, and quiet and signaling `NAN` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FloatClass {
+    /// A normal value
+    Normal,
+    /// A subnormal value, of either sign
+    Subnormal,
+    /// `0.0`
+    Zero,
+    /// `-0.0`
+    NegZero,
+    /// `INFINITY`
+    Infinity,
+    /// `NEG_INFINITY`
+    NegInfinity,
+    /// A quiet `NAN`, of either sign and with any payload
+    QuietNan,
+    /// A signaling `NAN`, of either sign and with any payload
+    SignalingNan,
+}
+
+impl core::fmt::Display for FloatClass {
+    /// Shows the name of the class, for instance `SignalingNan`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Classify an f32, see [FloatClass].
+pub fn classify_f32(x: f32) -> FloatClass {
+    use core::num::FpCategory;
+
+    match x.classify() {
+        FpCategory::Normal => FloatClass::Normal,
+        FpCategory::Subnormal => FloatClass::Subnormal,
+        FpCategory::Zero if x.is_sign_negative() => FloatClass::NegZero,
+        FpCategory::Zero => FloatClass::Zero,
+        FpCategory::Infinite if x.is_sign_negative() => FloatClass::NegInfinity,
+        FpCategory::Infinite => FloatClass::Infinity,
+        FpCategory::Nan if f32_is_signaling_nan(x) => FloatClass::SignalingNan,
+        FpCategory::Nan => FloatClass::QuietNan,
+    }
+}
+
+/// Classify an f64, see [FloatClass].
+pub fn classify_f64(x: f64) -> FloatClass {
+    use core::num::FpCategory;
+
+    match x.classify() {
+        FpCategory::Normal => FloatClass::Normal,
+        FpCategory::Subnormal => FloatClass::Subnormal,
+        FpCategory::Zero if x.is_sign_negative() => FloatClass::NegZero,
+        FpCategory::Zero => FloatClass::Zero,
+        FpCategory::Infinite if x.is_sign_negative() => FloatClass::NegInfinity,
+        FpCategory::Infinite => FloatClass::Infinity,
+        FpCategory::Nan if f64_is_signaling_nan(x) => FloatClass::SignalingNan,
+        FpCategory::Nan => FloatClass::QuietNan,
+    }
+}
@@ -1,21 +1,45 @@
 //! A global, thread-local [Wdg] instance.
+//!
+//! With the `global-sync` feature, the global instance is instead shared by
+//! all threads, behind a `Mutex`. This makes the output reproducible across
+//! threads from a single call to [seed], at the cost of some contention.
+//!
+//! Without `std`, the `no-std-global` feature puts the global instance behind
+//! a spinlock instead, see the crate level documentation.
 
+#[cfg(feature = "std")]
 use fastrand as fr;
 use paste::paste;
 
-use crate::Wdg;
+use crate::{FloatCategoryCounts, FloatWeights, Wdg, Weird};
 
+#[cfg(feature = "alloc")]
+use crate::{Endian, MatrixMode};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "global-sync")]
+use std::sync::{Mutex, OnceLock, PoisonError};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime};
 
 // clippy is not aware that deriving Default is only possible when no std
 // because Rng does not implement in no std either
+#[cfg(feature = "std")]
 #[allow(clippy::derivable_impls)]
 impl Default for Wdg {
     fn default() -> Self {
-        Self(fr::Rng::default())
+        Self(fr::Rng::default(), FloatWeights::default())
     }
 }
 
+#[cfg(feature = "std")]
 impl Wdg {
     /// Create a new Wdg by forking the global Wdg.
     ///
@@ -25,13 +49,15 @@ impl Wdg {
     }
 }
 
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 thread_local! {
     /// Likely to be truly random, using system provided entropy. It may be
     /// based on a default seed if the system entropy isn't available.
-    static GLOBAL_WDG: Cell<Wdg> = Cell::new(Wdg(fr::Rng::new()));
+    static GLOBAL_WDG: Cell<Wdg> = Cell::new(Wdg(fr::Rng::new(), FloatWeights::default()));
 }
 
 /// Run an operation with the current thread-local generator.
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 fn with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> R {
     GLOBAL_WDG.with(|wdg| {
         let current = wdg.replace(Wdg::with_seed(0));
@@ -41,6 +67,7 @@ fn with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> R {
 }
 
 /// Try to run an operation with the current thread-local generator.
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 fn try_with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> Result<R, std::thread::AccessError> {
     GLOBAL_WDG.try_with(|wdg| {
         let current = wdg.replace(Wdg::with_seed(0));
@@ -50,27 +77,69 @@ fn try_with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> Result<R, std::thread::Acce
 }
 
 /// Make sure the original WDG is restored even on panic.
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 struct RestoreOnDrop<'a> {
     wdg: &'a Cell<Wdg>,
     current: Wdg,
 }
 
+#[cfg(all(feature = "std", not(feature = "global-sync")))]
 impl Drop for RestoreOnDrop<'_> {
     fn drop(&mut self) {
-        self.wdg.set(Wdg(self.current.0.clone()));
+        self.wdg.set(self.current.clone());
     }
 }
 
-/// Initialize the thread-local generator with the given seed.
+/// Likely to be truly random, using system provided entropy. It may be
+/// based on a default seed if the system entropy isn't available.
+#[cfg(feature = "global-sync")]
+static GLOBAL_WDG: OnceLock<Mutex<Wdg>> = OnceLock::new();
+
+/// Run an operation with the generator shared by all threads.
+#[cfg(feature = "global-sync")]
+fn with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> R {
+    let mutex = GLOBAL_WDG.get_or_init(|| Mutex::new(Wdg(fr::Rng::new(), FloatWeights::default())));
+
+    // a panic while holding the lock can't leave the generator in an invalid
+    // state, so it's fine to ignore poisoning
+    let mut wdg = mutex.lock().unwrap_or_else(PoisonError::into_inner);
+    f(&mut wdg)
+}
+
+/// Try to run an operation with the generator shared by all threads.
+#[cfg(feature = "global-sync")]
+fn try_with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> Result<R, std::convert::Infallible> {
+    Ok(with_wdg(f))
+}
+
+/// Without system entropy, the generator starts from a fixed seed. It is
+/// created lazily, as `Wdg::with_seed` is not `const`.
+#[cfg(not(feature = "std"))]
+static GLOBAL_WDG: spin::Mutex<Option<Wdg>> = spin::Mutex::new(None);
+
+/// Run an operation with the generator behind the spinlock.
+#[cfg(not(feature = "std"))]
+fn with_wdg<R>(f: impl FnOnce(&mut Wdg) -> R) -> R {
+    let mut wdg = GLOBAL_WDG.lock();
+    f(wdg.get_or_insert_with(|| Wdg::with_seed(0x0d_6a_b0_f1_c7_ff_b9_1b)))
+}
+
+/// Initialize the global generator with the given seed.
 pub fn seed(seed: u64) {
     with_wdg(|wdg| wdg.seed(seed));
 }
 
-/// Gives back the _current_ seed that is being held by the thread-local generator.
+/// Gives back the _current_ seed that is being held by the global generator.
 pub fn get_seed() -> u64 {
     with_wdg(|wdg| wdg.get_seed())
 }
 
+/// Advance the global generator as if `n` random `u64` had been drawn from
+/// it, see [Wdg::jump].
+pub fn jump(n: u64) {
+    with_wdg(|wdg| wdg.jump(n));
+}
+
 // Generates a random f32 `NAN` value.
 ///
 /// There are multiple bit patterns that are equivalent to a `NAN`.
@@ -89,6 +158,120 @@ pub fn nan_f64() -> f64 {
     with_wdg(|wdg| wdg.nan_f64())
 }
 
+/// The canonical f32 quiet `NAN`, with a positive sign and an empty payload.
+///
+/// This has the same bit pattern as `f32::NAN`, and it does not use any
+/// randomness. Use [nan_f32] for `NAN` values with all payloads.
+pub fn canonical_nan_f32() -> f32 {
+    Wdg::canonical_nan_f32()
+}
+
+/// The canonical f64 quiet `NAN`, with a positive sign and an empty payload.
+///
+/// This has the same bit pattern as `f64::NAN`, and it does not use any
+/// randomness. Use [nan_f64] for `NAN` values with all payloads.
+pub fn canonical_nan_f64() -> f64 {
+    Wdg::canonical_nan_f64()
+}
+
+/// Generates an f32 `NAN` value with the given payload.
+///
+/// The low 22 bits of `payload` are used as the payload, the rest is
+/// ignored. Only the sign and the quiet bit are random. A zero payload is
+/// replaced by 1, so that the result is always a `NAN`, even when the
+/// quiet bit is clear.
+pub fn nan_f32_with_payload(payload: u32) -> f32 {
+    with_wdg(|wdg| wdg.nan_f32_with_payload(payload))
+}
+
+/// Generates an f64 `NAN` value with the given payload.
+///
+/// The low 51 bits of `payload` are used as the payload, the rest is
+/// ignored. Only the sign and the quiet bit are random. A zero payload is
+/// replaced by 1, so that the result is always a `NAN`, even when the
+/// quiet bit is clear. This is useful for testing NaN-boxing.
+pub fn nan_f64_with_payload(payload: u64) -> f64 {
+    with_wdg(|wdg| wdg.nan_f64_with_payload(payload))
+}
+
+/// Generates a random f32 quiet `NAN` value.
+///
+/// A quiet `NAN` has the most significant bit of the mantissa set. The
+/// sign and the remaining payload bits are random.
+pub fn quiet_nan_f32() -> f32 {
+    with_wdg(|wdg| wdg.quiet_nan_f32())
+}
+
+/// Generates a random f64 quiet `NAN` value.
+///
+/// A quiet `NAN` has the most significant bit of the mantissa set. The
+/// sign and the remaining payload bits are random.
+pub fn quiet_nan_f64() -> f64 {
+    with_wdg(|wdg| wdg.quiet_nan_f64())
+}
+
+/// Generates a random f32 signaling `NAN` value.
+///
+/// A signaling `NAN` has the most significant bit of the mantissa clear.
+/// The sign and the remaining payload bits are random.
+pub fn signaling_nan_f32() -> f32 {
+    with_wdg(|wdg| wdg.signaling_nan_f32())
+}
+
+/// Generates a random f64 signaling `NAN` value.
+///
+/// A signaling `NAN` has the most significant bit of the mantissa clear.
+/// The sign and the remaining payload bits are random.
+pub fn signaling_nan_f64() -> f64 {
+    with_wdg(|wdg| wdg.signaling_nan_f64())
+}
+
+/// Generates a random f32 that a plain uniform float generator would never
+/// produce.
+///
+/// This only generates `-0.0` and `NAN` values other than the canonical
+/// `f32::NAN`, which includes signaling `NAN` values, negative `NAN` values
+/// and quiet `NAN` values with non-empty payloads.
+pub fn exotic_f32() -> f32 {
+    with_wdg(|wdg| wdg.exotic_f32())
+}
+
+/// Generates a random f64 that a plain uniform float generator would never
+/// produce.
+///
+/// This only generates `-0.0` and `NAN` values other than the canonical
+/// `f64::NAN`, which includes signaling `NAN` values, negative `NAN` values
+/// and quiet `NAN` values with non-empty payloads.
+pub fn exotic_f64() -> f64 {
+    with_wdg(|wdg| wdg.exotic_f64())
+}
+
+/// Check whether `f` preserves signaling `NAN` values.
+///
+/// A random signaling `NAN` is generated with [signaling_nan_f32] and passed
+/// to `f`, this returns `true` if the output of `f` is still a signaling
+/// `NAN`. Note that most arithmetic operations turn a signaling `NAN` into a
+/// quiet one, and so can some hardware when merely loading or storing the
+/// value.
+pub fn preserves_signaling_nan_f32(f: impl FnOnce(f32) -> f32) -> bool {
+    // call `f` outside of the global generator, as it may use it too
+    let x = signaling_nan_f32();
+    crate::float_utils::f32_is_signaling_nan(f(x))
+}
+
+/// Check whether `f` preserves signaling `NAN` values.
+///
+/// A random signaling `NAN` is generated with [signaling_nan_f64] and passed
+/// to `f`, this returns `true` if the output of `f` is still a signaling
+/// `NAN`. Note that most arithmetic operations turn a signaling `NAN` into a
+/// quiet one, and so can some hardware when merely loading or storing the
+/// value.
+pub fn preserves_signaling_nan_f64(f: impl FnOnce(f64) -> f64) -> bool {
+    // call `f` outside of the global generator, as it may use it too
+    let x = signaling_nan_f64();
+    crate::float_utils::f64_is_signaling_nan(f(x))
+}
+
 /// Generates a random f32 denormal value.
 ///
 /// This generator covers all possible denormal values as specified in
@@ -105,6 +288,26 @@ pub fn subnormal_f64() -> f64 {
     with_wdg(|wdg| wdg.subnormal_f64())
 }
 
+/// Generates a random f32 denormal value at the boundaries of the
+/// denormal range.
+///
+/// The smallest denormal, right above zero, and the largest denormal,
+/// right below `MIN_POSITIVE`, are much more common than normal, as are
+/// the few denormals right next to them. The sign is random.
+pub fn subnormal_boundary_f32() -> f32 {
+    with_wdg(|wdg| wdg.subnormal_boundary_f32())
+}
+
+/// Generates a random f64 denormal value at the boundaries of the
+/// denormal range.
+///
+/// The smallest denormal, right above zero, and the largest denormal,
+/// right below `MIN_POSITIVE`, are much more common than normal, as are
+/// the few denormals right next to them. The sign is random.
+pub fn subnormal_boundary_f64() -> f64 {
+    with_wdg(|wdg| wdg.subnormal_boundary_f64())
+}
+
 /// Generate a random f32 normal value
 pub fn normal_f32() -> f32 {
     with_wdg(|wdg| wdg.normal_f32())
@@ -133,6 +336,42 @@ pub fn special_f64() -> f64 {
     with_wdg(|wdg| wdg.special_f64())
 }
 
+/// Generate a random f32 within a few ULPs of `x`.
+///
+/// The result is `x` stepped by up to 3 ULPs in a random direction, see
+/// [crate::float_utils::f32_step_ulps] for how zero, the infinities and
+/// `NAN` are handled.
+pub fn ulp_neighbor_f32(x: f32) -> f32 {
+    with_wdg(|wdg| wdg.ulp_neighbor_f32(x))
+}
+
+/// Generate a random f64 within a few ULPs of `x`.
+///
+/// The result is `x` stepped by up to 3 ULPs in a random direction, see
+/// [crate::float_utils::f64_step_ulps] for how zero, the infinities and
+/// `NAN` are handled.
+pub fn ulp_neighbor_f64(x: f64) -> f64 {
+    with_wdg(|wdg| wdg.ulp_neighbor_f64(x))
+}
+
+/// Generate a random f32 within a few ULPs of an "interesting" value.
+///
+/// The interesting values are the powers of two, including subnormal
+/// ones, and the "special" values of [special_f32]. Neighbors of zero
+/// cross over to the other sign.
+pub fn weird_near_f32() -> f32 {
+    with_wdg(|wdg| wdg.weird_near_f32())
+}
+
+/// Generate a random f64 within a few ULPs of an "interesting" value.
+///
+/// The interesting values are the powers of two, including subnormal
+/// ones, and the "special" values of [special_f64]. Neighbors of zero
+/// cross over to the other sign.
+pub fn weird_near_f64() -> f64 {
+    with_wdg(|wdg| wdg.weird_near_f64())
+}
+
 /// Generate a random f32, such that special or problematic values are much
 /// more common than normal.
 ///
@@ -169,6 +408,750 @@ pub fn f64() -> f64 {
     with_wdg(|wdg| wdg.f64())
 }
 
+/// Generate the bit pattern of a random f32, with the same distribution as
+/// [f32()].
+///
+/// `f32::from_bits` of the result gives back exactly the generated value,
+/// including the payload of signaling `NAN` values.
+pub fn weird_f32_bits() -> u32 {
+    with_wdg(|wdg| wdg.weird_f32_bits())
+}
+
+/// Generate the bit pattern of a random f64, with the same distribution as
+/// [f64()].
+///
+/// `f64::from_bits` of the result gives back exactly the generated value,
+/// including the payload of signaling `NAN` values.
+pub fn weird_f64_bits() -> u64 {
+    with_wdg(|wdg| wdg.weird_f64_bits())
+}
+
+/// Generate a sequence of `n` f32 values that maximizes the error of naive
+/// summation.
+///
+/// The sequences are made of a big value, and many tiny terms that are all
+/// lost by a naive left to right sum, see
+/// [Wdg::weird_f32_accumulation_sequence].
+#[cfg(feature = "alloc")]
+pub fn weird_f32_accumulation_sequence(n: usize) -> Vec<f32> {
+    with_wdg(|wdg| wdg.weird_f32_accumulation_sequence(n))
+}
+
+/// Generate a random f64 near a boundary of float to integer conversions,
+/// such as the ones done by `as` casts and `try_into`.
+///
+/// The boundaries are the limits of every integer type, `2^24` and `2^53`,
+/// above which not every integer fits in an f32 or an f64, and their
+/// negative counterparts. See [Wdg::weird_f64_near_integer] for the full
+/// list.
+pub fn weird_f64_near_integer() -> f64 {
+    with_wdg(|wdg| wdg.weird_f64_near_integer())
+}
+
+/// Generate a random f64 angle, in radians, that stresses trigonometric
+/// functions like `sin`, `cos` and `tan`.
+///
+/// The angles are signed zeros, multiples of `π/2` and `π`, the f64 nearest
+/// to large multiples of `π/2`, and huge values like `1e300`, which stress
+/// argument reduction. See [Wdg::weird_f64_angle] for the full list.
+pub fn weird_f64_angle() -> f64 {
+    with_wdg(|wdg| wdg.weird_f64_angle())
+}
+
+/// Generate a random pair of f32 values that stress comparison and sorting
+/// code.
+///
+/// The pairs are the cases where partial order, total order and equality
+/// disagree, like two `NAN` values, `-0.0` and `0.0`, `INFINITY` and `MAX`,
+/// and two values one ULP apart.
+pub fn weird_f32_pair_for_comparison() -> (f32, f32) {
+    with_wdg(|wdg| wdg.weird_f32_pair_for_comparison())
+}
+
+/// Generate a random pair of f64 values that stress comparison and sorting
+/// code.
+///
+/// The pairs are the cases where partial order, total order and equality
+/// disagree, like two `NAN` values, `-0.0` and `0.0`, `INFINITY` and `MAX`,
+/// and two values one ULP apart.
+pub fn weird_f64_pair_for_comparison() -> (f64, f64) {
+    with_wdg(|wdg| wdg.weird_f64_pair_for_comparison())
+}
+
+/// Generate a random f32, such that every class of float is guaranteed to be
+/// covered in a bounded number of draws.
+///
+/// Here, `index` is the number of the draw, and every 16 consecutive indices
+/// generate at least one value of every
+/// [FloatClass](crate::float_utils::FloatClass), including both quiet and
+/// signaling `NAN` values.
+pub fn f32_covering(index: u64) -> f32 {
+    with_wdg(|wdg| wdg.f32_covering(index))
+}
+
+/// Generate a random f64, such that every class of float is guaranteed to be
+/// covered in a bounded number of draws.
+///
+/// Here, `index` is the number of the draw, and every 16 consecutive indices
+/// generate at least one value of every
+/// [FloatClass](crate::float_utils::FloatClass), including both quiet and
+/// signaling `NAN` values.
+pub fn f64_covering(index: u64) -> f64 {
+    with_wdg(|wdg| wdg.f64_covering(index))
+}
+
+/// Generate a random finite f32 in `lo..=hi`, such that special or
+/// problematic values are much more common than normal.
+///
+/// The bounds, their neighbors inside the range, `0.0` and subnormal
+/// values are much more common than normal, as long as they are in the
+/// range. The bounds can be given in any order, and the result never
+/// falls outside of them.
+///
+/// # Panics
+///
+/// Panics if either bound is not finite.
+pub fn weird_f32_in(lo: f32, hi: f32) -> f32 {
+    with_wdg(|wdg| wdg.weird_f32_in(lo, hi))
+}
+
+/// Generate a random finite f64 in `lo..=hi`, such that special or
+/// problematic values are much more common than normal.
+///
+/// The bounds, their neighbors inside the range, `0.0` and subnormal
+/// values are much more common than normal, as long as they are in the
+/// range. The bounds can be given in any order, and the result never
+/// falls outside of them.
+///
+/// # Panics
+///
+/// Panics if either bound is not finite.
+pub fn weird_f64_in(lo: f64, hi: f64) -> f64 {
+    with_wdg(|wdg| wdg.weird_f64_in(lo, hi))
+}
+
+/// Generate a random `char` "special" value
+///
+/// A special value is what I call specific scalar values that are unique and
+/// are pretty much impossible to generate by chance, and have some unusual
+/// properties. For instance `'\0'`, the largest scalar value, the scalars
+/// right next to the surrogate gap, the replacement character and the BOM.
+pub fn special_char() -> char {
+    with_wdg(|wdg| wdg.special_char())
+}
+
+/// Generate a random `char`, such that special or problematic values are
+/// much more common than normal.
+///
+/// The non-special values are sampled uniformly from all valid Unicode
+/// scalar values, so a surrogate is never produced.
+pub fn char() -> char {
+    with_wdg(|wdg| wdg.char())
+}
+
+/// Draw `n` values with [f32()] and count how many fall in each category.
+///
+/// This is useful for checking the distribution of the generator in
+/// your own tests.
+pub fn sample_f32_categories(n: usize) -> FloatCategoryCounts {
+    with_wdg(|wdg| wdg.sample_f32_categories(n))
+}
+
+/// Generate a random f32, picking the category of the value proportionally
+/// to the given weights.
+///
+/// See [FloatWeights] for more details.
+///
+/// # Panics
+///
+/// Panics if all weights are zero.
+pub fn f32_weighted(weights: &FloatWeights) -> f32 {
+    with_wdg(|wdg| wdg.f32_weighted(weights))
+}
+
+/// Generate a random f64, picking the category of the value proportionally
+/// to the given weights.
+///
+/// See [FloatWeights] for more details.
+///
+/// # Panics
+///
+/// Panics if all weights are zero.
+pub fn f64_weighted(weights: &FloatWeights) -> f64 {
+    with_wdg(|wdg| wdg.f64_weighted(weights))
+}
+
+/// Generate a random finite f32, such that special or problematic values
+/// are much more common than normal.
+///
+/// This is like [f32()], but it never generates `NAN` or infinities.
+///
+/// The distribution is as follows:
+/// - 33% normal values
+/// - 33% subnormal values
+/// - 33% finite "special" values, such as `-0.0` and `MAX`
+pub fn finite_f32() -> f32 {
+    with_wdg(|wdg| wdg.finite_f32())
+}
+
+/// Generate a random finite f64, such that special or problematic values
+/// are much more common than normal.
+///
+/// This is like [f64()], but it never generates `NAN` or infinities.
+///
+/// The distribution is as follows:
+/// - 33% normal values
+/// - 33% subnormal values
+/// - 33% finite "special" values, such as `-0.0` and `MAX`
+pub fn finite_f64() -> f64 {
+    with_wdg(|wdg| wdg.finite_f64())
+}
+
+/// Generate a random positive f32, such that special or problematic
+/// values are much more common than normal.
+///
+/// This only ever generates values `> 0.0`, so it never generates `0.0`
+/// or `NAN`, but it does generate `INFINITY`.
+pub fn positive_f32() -> f32 {
+    with_wdg(|wdg| wdg.positive_f32())
+}
+
+/// Generate a random non-negative f32, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is like [positive_f32], but it also generates `0.0`. It never
+/// generates `-0.0` or `NAN`.
+pub fn non_negative_f32() -> f32 {
+    with_wdg(|wdg| wdg.non_negative_f32())
+}
+
+/// Generate a random negative f32, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is the mirror image of [positive_f32], it only ever generates
+/// values `< 0.0`, including `NEG_INFINITY`.
+pub fn negative_f32() -> f32 {
+    with_wdg(|wdg| wdg.negative_f32())
+}
+
+/// Generate a random non-positive f32, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is the mirror image of [non_negative_f32], it also generates
+/// `-0.0`, but never `0.0` or `NAN`.
+pub fn non_positive_f32() -> f32 {
+    with_wdg(|wdg| wdg.non_positive_f32())
+}
+
+/// Generate a random positive f64, such that special or problematic
+/// values are much more common than normal.
+///
+/// This only ever generates values `> 0.0`, so it never generates `0.0`
+/// or `NAN`, but it does generate `INFINITY`.
+pub fn positive_f64() -> f64 {
+    with_wdg(|wdg| wdg.positive_f64())
+}
+
+/// Generate a random non-negative f64, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is like [positive_f64], but it also generates `0.0`. It never
+/// generates `-0.0` or `NAN`.
+pub fn non_negative_f64() -> f64 {
+    with_wdg(|wdg| wdg.non_negative_f64())
+}
+
+/// Generate a random negative f64, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is the mirror image of [positive_f64], it only ever generates
+/// values `< 0.0`, including `NEG_INFINITY`.
+pub fn negative_f64() -> f64 {
+    with_wdg(|wdg| wdg.negative_f64())
+}
+
+/// Generate a random non-positive f64, such that special or problematic
+/// values are much more common than normal.
+///
+/// This is the mirror image of [non_negative_f64], it also generates
+/// `-0.0`, but never `0.0` or `NAN`.
+pub fn non_positive_f64() -> f64 {
+    with_wdg(|wdg| wdg.non_positive_f64())
+}
+
+/// Generate a random sign for an f32, either `1.0` or `-1.0`, with equal
+/// probability.
+pub fn sign_f32() -> f32 {
+    with_wdg(|wdg| wdg.sign_f32())
+}
+
+/// Generate a random signed zero f32, either `0.0` or `-0.0`, with equal
+/// probability.
+pub fn signed_zero_f32() -> f32 {
+    with_wdg(|wdg| wdg.signed_zero_f32())
+}
+
+/// Generate a random sign for an f64, either `1.0` or `-1.0`, with equal
+/// probability.
+pub fn sign_f64() -> f64 {
+    with_wdg(|wdg| wdg.sign_f64())
+}
+
+/// Generate a random signed zero f64, either `0.0` or `-0.0`, with equal
+/// probability.
+pub fn signed_zero_f64() -> f64 {
+    with_wdg(|wdg| wdg.signed_zero_f64())
+}
+
+/// Overwrite every element of `slice` with a weird value, generated with
+/// [Weird::weird].
+///
+/// This is useful for reusing a buffer instead of allocating a new one.
+pub fn fill<T: Weird>(slice: &mut [T]) {
+    with_wdg(|wdg| wdg.fill(slice))
+}
+
+#[cfg(feature = "alloc")]
+/// Append `n` weird values, generated with [Weird::weird], to any
+/// collection that implements [Extend], like a `Vec`, a `VecDeque` or a
+/// `HashSet`.
+pub fn extend_weird<T: Weird, C: Extend<T>>(collection: &mut C, n: usize) {
+    with_wdg(|wdg| wdg.extend_weird(collection, n))
+}
+
+/// Generate a pair of weird values, with [Weird::weird].
+pub fn weird_pair<A: Weird, B: Weird>() -> (A, B) {
+    with_wdg(|wdg| wdg.weird_pair())
+}
+
+/// Generate a triple of weird values, with [Weird::weird].
+pub fn weird_triple<A: Weird, B: Weird, C: Weird>() -> (A, B, C) {
+    with_wdg(|wdg| wdg.weird_triple())
+}
+
+/// Generate a quadruple of weird values, with [Weird::weird].
+pub fn weird_quad<A: Weird, B: Weird, C: Weird, D: Weird>() -> (A, B, C, D) {
+    with_wdg(|wdg| wdg.weird_quad())
+}
+
+/// Fill a byte slice with random bytes, such that boundary bytes like
+/// `0x00`, `0xFF`, `0x7F` and `0x80` are much more common than normal.
+///
+/// About half of the bytes are picked from the boundary bytes, the rest
+/// are uniformly random. This is much faster than calling [u8()] for
+/// each byte, as the randomness is drawn in bulk.
+///
+/// Returns the number of boundary bytes that were inserted. Note that a
+/// uniformly random byte may also happen to be a boundary byte, these are
+/// not counted.
+pub fn fill_weird_bytes(bytes: &mut [u8]) -> usize {
+    with_wdg(|wdg| wdg.fill_weird_bytes(bytes))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `String` packed with legal but problematic content.
+///
+/// The string contains up to `max_len` chars, mixing weird chars from
+/// [char()], combining diacritics, zero-width joiners, right-to-left
+/// marks, emoji outside of the BMP, `'\0'`, newline variants, and
+/// grapheme clusters made of multiple code points. The length is biased
+/// towards 0, 1 and `max_len`.
+///
+/// Note that the length is counted in chars, not in bytes.
+pub fn weird_string(max_len: usize) -> String {
+    with_wdg(|wdg| wdg.weird_string(max_len))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a decimal string that lands on or right next to a rounding
+/// boundary of f32, together with its correctly rounded f32 value.
+///
+/// The strings include ties between adjacent f32 values, values right next
+/// to ties, and ties broken only by the last of hundreds of digits, see
+/// [Wdg::weird_f32_from_decimal_string].
+pub fn weird_f32_from_decimal_string() -> (String, f32) {
+    with_wdg(|wdg| wdg.weird_f32_from_decimal_string())
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `String` of weird ASCII chars, which never contains a
+/// byte `>= 0x80`.
+///
+/// The string contains up to `max_len` chars, mixing printable ASCII with
+/// control chars, `DEL`, and the chars that tend to confuse protocols and
+/// text processing, like `'\t'`, `'\r'`, `'\n'` and `'\0'`.
+pub fn weird_ascii_string(max_len: usize) -> String {
+    with_wdg(|wdg| wdg.weird_ascii_string(max_len))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `String` with multi-byte chars, and a byte index into it
+/// that is NOT a char boundary.
+///
+/// Slicing a `&str` at the index panics. Half of the time, the index lands in
+/// the middle of a 4 byte char, like an emoji.
+pub fn weird_utf8_with_split_points() -> (String, usize) {
+    with_wdg(|wdg| wdg.weird_utf8_with_split_points())
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `String` packed with the chars that are tricky to
+/// escape in JSON.
+///
+/// The string contains up to `max_len` chars, mixing quotes, backslashes,
+/// control chars, the forward slash, chars outside of the BMP, text that
+/// looks like an escape sequence, and `'\u{2028}'` and `'\u{2029}'`.
+pub fn weird_json_string(max_len: usize) -> String {
+    with_wdg(|wdg| wdg.weird_json_string(max_len))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `Cow<str>`, which is borrowed or owned with equal
+/// probability.
+///
+/// The borrowed strings come from a small table of nasty static strings,
+/// like the empty string, whitespace only strings and a string of 1024
+/// chars. The owned strings are generated with [weird_string], with at most
+/// `max_len` chars.
+pub fn weird_cow_str(max_len: usize) -> Cow<'static, str> {
+    with_wdg(|wdg| wdg.weird_cow_str(max_len))
+}
+
+/// Generate a random byte buffer that looks almost like a sequence of
+/// binary records.
+///
+/// The buffer is a concatenation of weird integers of all sizes, the bit
+/// patterns of weird floats, including `NAN`, and length-prefixed chunks
+/// of bytes where the `u32` length prefix often lies about the length of
+/// the chunk. The integers, floats and length prefixes are encoded with
+/// the given byte order. The buffer is at most `max_len` bytes long, and
+/// the last record is often cut short.
+#[cfg(feature = "alloc")]
+pub fn weird_blob(max_len: usize, endian: Endian) -> Vec<u8> {
+    with_wdg(|wdg| wdg.weird_blob(max_len, endian))
+}
+
+/// Generate a matrix of `rows * cols` weird f64 values, as a flat buffer in
+/// row-major order.
+///
+/// The `mode` selects the structure of the matrix, see [MatrixMode].
+#[cfg(feature = "alloc")]
+pub fn weird_matrix_f64(rows: usize, cols: usize, mode: MatrixMode) -> Vec<f64> {
+    with_wdg(|wdg| wdg.weird_matrix_f64(rows, cols, mode))
+}
+
+/// Generate a byte buffer of length `len` that is mostly the `fill` byte,
+/// with a few positions flipped to weird bytes, like `0xFF` and the other
+/// boundary bytes of [u8()].
+///
+/// The density of the flips is random, often a single flip, sometimes many,
+/// see [bytes_with_pattern_density] to control it.
+#[cfg(feature = "alloc")]
+pub fn bytes_with_pattern(len: usize, fill: u8) -> Vec<u8> {
+    with_wdg(|wdg| wdg.bytes_with_pattern(len, fill))
+}
+
+/// Generate a byte buffer of length `len` that is made of the `fill` byte,
+/// where every position is flipped to a weird byte with probability
+/// `density`.
+#[cfg(feature = "alloc")]
+pub fn bytes_with_pattern_density(len: usize, fill: u8, density: f64) -> Vec<u8> {
+    with_wdg(|wdg| wdg.bytes_with_pattern_density(len, fill, density))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random byte sequence that is NOT valid UTF-8.
+///
+/// The sequence is at most `max_len` bytes long, and contains at least one
+/// of the following errors, mixed with valid chars:
+/// - lone continuation bytes, in `0x80..=0xBF`
+/// - lead bytes of multi-byte sequences, truncated before their end
+/// - overlong encodings of ASCII chars
+/// - encoded surrogate code points, in `0xD800..=0xDFFF`
+///
+/// The length is biased towards 1 and `max_len`.
+///
+/// # Panics
+///
+/// Panics if `max_len` is 0, as the empty sequence is valid UTF-8.
+pub fn invalid_utf8(max_len: usize) -> Vec<u8> {
+    with_wdg(|wdg| wdg.invalid_utf8(max_len))
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `Duration` "special" value
+///
+/// The special values are `Duration::ZERO`, `Duration::MAX`, one
+/// nanosecond, exactly one second, the largest duration below one second
+/// and the largest whole number of seconds.
+pub fn special_duration() -> Duration {
+    with_wdg(|wdg| wdg.special_duration())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `Duration`, such that special or problematic values
+/// are much more common than normal.
+///
+/// Besides the values of [special_duration], this often generates
+/// durations with 999_999_999 nanoseconds, right before a carry into the
+/// seconds. The seconds are generated with [u64()], so they are often
+/// close to `u64::MAX`.
+pub fn duration() -> Duration {
+    with_wdg(|wdg| wdg.duration())
+}
+
+#[cfg(feature = "std")]
+/// Write `n` weird bytes to `writer`, generated like with
+/// [fill_weird_bytes].
+///
+/// The bytes are streamed in chunks of 4 KiB, so the memory used is
+/// constant, no matter how large `n` is.
+///
+/// # Errors
+///
+/// Returns the first error returned by `writer`.
+pub fn write_weird_bytes(writer: &mut impl std::io::Write, n: usize) -> std::io::Result<()> {
+    with_wdg(|wdg| wdg.write_weird_bytes(writer, n))
+}
+
+#[cfg(feature = "std")]
+/// Generate a random pair of `Duration`, for testing interval arithmetic.
+///
+/// The pairs are biased towards the cases where subtraction underflows or
+/// addition overflows: the second is often larger than the first, often by a
+/// single nanosecond, both are often equal, and both are sometimes
+/// `Duration::MAX`.
+pub fn weird_duration_pair() -> (Duration, Duration) {
+    with_wdg(|wdg| wdg.weird_duration_pair())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `SystemTime`, such that special or problematic values
+/// are much more common than normal.
+///
+/// The values include the UNIX epoch exactly, one nanosecond before and
+/// after it, the end of the year 9999, the 32-bit overflow points of
+/// 2038 and 2106, the year 1900, and the earliest and latest times the
+/// platform can represent. Times far from the epoch are clamped to the
+/// representable range, so this never panics.
+pub fn system_time() -> SystemTime {
+    with_wdg(|wdg| wdg.system_time())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `Ipv4Addr`, such that special or problematic values
+/// are much more common than normal.
+///
+/// The special values are the unspecified address `0.0.0.0`, the
+/// broadcast address `255.255.255.255`, loopback addresses, link-local
+/// addresses, private addresses and multicast addresses.
+pub fn ipv4_addr() -> Ipv4Addr {
+    with_wdg(|wdg| wdg.ipv4_addr())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `Ipv6Addr`, such that special or problematic values
+/// are much more common than normal.
+///
+/// The special values are the unspecified address `::`, the loopback
+/// address `::1`, link-local addresses, multicast addresses, the address
+/// with all bits set, and IPv4-mapped (`::ffff:a.b.c.d`) and
+/// IPv4-compatible (`::a.b.c.d`) addresses of weird IPv4 addresses.
+pub fn ipv6_addr() -> Ipv6Addr {
+    with_wdg(|wdg| wdg.ipv6_addr())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `IpAddr`, either V4 or V6, such that special or
+/// problematic values are much more common than normal.
+///
+/// See [ipv4_addr] and [ipv6_addr].
+pub fn ip_addr() -> IpAddr {
+    with_wdg(|wdg| wdg.ip_addr())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random port, such that special or problematic values are
+/// much more common than normal.
+///
+/// The special values are 0, 1, 80, 443 and 65535.
+pub fn port() -> u16 {
+    with_wdg(|wdg| wdg.port())
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `SocketAddr`, such that special or problematic
+/// values are much more common than normal.
+///
+/// See [ip_addr] and [port].
+pub fn socket_addr() -> SocketAddr {
+    with_wdg(|wdg| wdg.socket_addr())
+}
+
+/// Generate a random `char` in `lo..=hi`, such that the bounds and the
+/// block boundaries within the range are much more common than normal.
+///
+/// The block boundaries are the scalars where the UTF-8 and UTF-16
+/// encoded lengths change, and the scalars right next to the surrogate
+/// gap. The other values are sampled uniformly, skipping the surrogate
+/// gap if the range straddles it.
+///
+/// # Panics
+///
+/// Panics if `lo > hi`.
+pub fn char_in_range(lo: char, hi: char) -> char {
+    with_wdg(|wdg| wdg.char_in_range(lo, hi))
+}
+
+/// Generate a random `char` from a random Unicode general category, such that
+/// every major category is equally likely.
+///
+/// The categories are letters, numbers, punctuation, symbols, combining
+/// marks, control chars, format chars and separators.
+pub fn weird_char_category() -> char {
+    with_wdg(|wdg| wdg.weird_char_category())
+}
+
+/// Generate a random ASCII control `char`, in `'\0'..='\u{1F}'` or `'\u{7F}'`
+pub fn control_char() -> char {
+    with_wdg(|wdg| wdg.control_char())
+}
+
+/// Generate a random whitespace `char`
+///
+/// All the chars with the Unicode `White_Space` property are equally
+/// likely, this includes the ASCII whitespace, but also the more exotic
+/// no-break spaces, the line separator `'\u{2028}'` and the paragraph
+/// separator `'\u{2029}'`.
+pub fn whitespace_char() -> char {
+    with_wdg(|wdg| wdg.whitespace_char())
+}
+
+/// Generate a random `bool`, `true` and `false` are equally likely.
+pub fn bool() -> bool {
+    with_wdg(|wdg| wdg.bool())
+}
+
+/// Generate `n` correlated booleans, for fuzzing flags that toggle a state.
+///
+/// The sequence is either a single run of the same value, a few long runs,
+/// or strictly alternating, see [Wdg::weird_bool_run].
+#[cfg(feature = "alloc")]
+pub fn weird_bool_run(n: usize) -> Vec<bool> {
+    with_wdg(|wdg| wdg.weird_bool_run(n))
+}
+
+/// Generate a random `bool`, which is `true` with probability `p_true`.
+///
+/// The probability is clamped to `0.0..=1.0`, and a `NAN` probability is
+/// treated as `0.0`. A probability of exactly `0.0` always gives `false`
+/// and exactly `1.0` always gives `true`.
+pub fn weird_bool(p_true: f64) -> bool {
+    with_wdg(|wdg| wdg.weird_bool(p_true))
+}
+
+/// Generate a random UTF-16 sequence, which is often NOT valid.
+///
+/// The sequence is at most `max_len` code units long, and mixes weird
+/// chars from [char()], correctly paired surrogates, the BOM and
+/// byte-swapped BOM code units (`0xFEFF` and `0xFFFE`), and the error
+/// cases of lenient decoders, which are the most common: unpaired high
+/// and low surrogates, and pairs in the wrong order. The length is
+/// biased towards 0, 1 and `max_len`.
+#[cfg(feature = "alloc")]
+pub fn weird_utf16(max_len: usize) -> Vec<u16> {
+    with_wdg(|wdg| wdg.weird_utf16(max_len))
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random grapheme cluster made of many code points.
+///
+/// The clusters are: a base letter with a stack of combining marks,
+/// often 10 or more, a pair of regional indicators (a flag), an emoji ZWJ
+/// sequence like a family, possibly with skin tone modifiers, a keycap
+/// sequence, a tag sequence flag like the one of England, a Hangul
+/// syllable made of conjoining jamo, and other common clusters like
+/// `"\r\n"`.
+pub fn weird_grapheme() -> String {
+    with_wdg(|wdg| wdg.weird_grapheme())
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random `String` made of `n` grapheme clusters from
+/// [weird_grapheme].
+pub fn weird_graphemes(n: usize) -> String {
+    with_wdg(|wdg| wdg.weird_graphemes(n))
+}
+
+/// Generate a random enum discriminant, for an enum with `count`
+/// variants, such that invalid discriminants are much more common than
+/// normal.
+///
+/// The values are biased towards 0, `count - 1`, `count` (the first
+/// invalid discriminant) and `u32::MAX`. About half of the values are
+/// valid discriminants. If `count` is 0, every value is invalid, so this
+/// is the same as [u32()].
+pub fn weird_enum_index(count: u32) -> u32 {
+    with_wdg(|wdg| wdg.weird_enum_index(count))
+}
+
+/// Generate a random index into a collection of length `len`, such that the
+/// indices at and past the bounds are much more common than normal.
+///
+/// The values are biased towards 0, `len - 1`, `len` (the first out of
+/// bounds index) and `usize::MAX`. About half of the values are valid
+/// indices. If `len` is 0, every index is out of bounds.
+pub fn weird_index_into(len: usize) -> usize {
+    with_wdg(|wdg| wdg.weird_index_into(len))
+}
+
+/// Generate a random length, such that problematic lengths are much more
+/// common than normal.
+///
+/// The length is at most `max`, and it is biased towards 0, 1, 2, `max - 1`
+/// and `max`. If `max` is 0, this always returns 0.
+pub fn weird_len(max: usize) -> usize {
+    with_wdg(|wdg| wdg.weird_len(max))
+}
+
+/// Generate a random line ending
+///
+/// All the following are equally likely: `"\n"`, `"\r\n"`, `"\r"`,
+/// `"\n\r"`, the line separator `"\u{2028}"`, the paragraph separator
+/// `"\u{2029}"` and the empty string.
+pub fn newline() -> &'static str {
+    with_wdg(|wdg| wdg.newline())
+}
+
+#[cfg(feature = "alloc")]
+/// Generate a random text made of `n` short lines, separated by a mix of
+/// different line endings.
+///
+/// Every line is made of up to 8 ASCII alphanumeric chars, and every line
+/// ending is picked independently with [newline], so a single text
+/// mixes `"\n"`, `"\r\n"`, `"\r"` and the other line endings. Half of
+/// the time, the text also ends with a line ending.
+pub fn weird_lines(n: usize) -> String {
+    with_wdg(|wdg| wdg.weird_lines(n))
+}
+
+#[cfg(feature = "std")]
+/// Generate a random `PathBuf` made of problematic components.
+///
+/// The components include empty segments (repeated separators), `.` and
+/// `..`, names right around `NAME_MAX` (255 bytes), names with leading,
+/// trailing or embedded spaces and newlines, and names that are not valid
+/// UTF-8. The path may be absolute or relative, and may end with a
+/// trailing separator.
+///
+/// On Unix, the non UTF-8 names are made of arbitrary bytes, so
+/// `to_str()` returns `None` for them. On Windows, they contain unpaired
+/// surrogates, and the paths may also have drive letters, UNC and verbatim
+/// prefixes, forward and backward slashes, and reserved names like `CON`.
+pub fn weird_path() -> PathBuf {
+    with_wdg(|wdg| wdg.weird_path())
+}
+
 macro_rules! int_uint {
     ($($t:ty),+ $(,)?) => {
         $(
@@ -198,8 +1181,165 @@ macro_rules! int_uint_inner {
             pub fn $t() -> $t {
                 with_wdg(|wdg| wdg.$t())
             }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// at or right next to the boundaries of the type, such as
+            /// `MAX - 1`
+            pub fn [<near_boundary_ $t>]() -> $t {
+                with_wdg(|wdg| wdg.[<near_boundary_ $t>]())
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// close to `target`
+            ///
+            /// The value is `target` plus or minus a small delta of at most 3,
+            /// saturating at the bounds of the type.
+            pub fn [<near_value_ $t>](target: $t) -> $t {
+                with_wdg(|wdg| wdg.[<near_value_ $t>](target))
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// in the given range, such that the edges of the range are much
+            /// more common than normal.
+            ///
+            /// The first value, the last value (`end - 1`) and the midpoint
+            /// of the range are each generated 20% of the time, the rest of
+            /// the time the value is uniformly random in the range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            pub fn [<weird_range_ $t>](range: core::ops::Range<$t>) -> $t {
+                with_wdg(|wdg| wdg.[<weird_range_ $t>](range))
+            }
+
+            /// Generate a random positive power of two that fits in
+            #[doc = concat!("`", stringify!($t), "`")]
+            ///
+            /// The exponent is uniformly random, so the values go from `1` up to the
+            /// largest power of two not exceeding `MAX`.
+            pub fn [<power_of_two_ $t>]() -> $t {
+                with_wdg(|wdg| wdg.[<power_of_two_ $t>]())
+            }
+
+            /// Generate a random positive power of ten that fits in
+            #[doc = concat!("`", stringify!($t), "`")]
+            ///
+            /// The exponent is uniformly random, so the values go from `1` up to the
+            /// largest power of ten not exceeding `MAX`.
+            pub fn [<power_of_ten_ $t>]() -> $t {
+                with_wdg(|wdg| wdg.[<power_of_ten_ $t>]())
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose sum overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like `MAX` and 1, or `MAX - 1` and 2.
+            pub fn [<overflowing_add_pair_ $t>]() -> ($t, $t) {
+                with_wdg(|wdg| wdg.[<overflowing_add_pair_ $t>]())
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose difference overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like 0 and 1 for unsigned integers.
+            pub fn [<overflowing_sub_pair_ $t>]() -> ($t, $t) {
+                with_wdg(|wdg| wdg.[<overflowing_sub_pair_ $t>]())
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose product overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like 2 and `MAX / 2 + 1`.
+            pub fn [<overflowing_mul_pair_ $t>]() -> ($t, $t) {
+                with_wdg(|wdg| wdg.[<overflowing_mul_pair_ $t>]())
+            }
+
+            /// Generate a random numerator and denominator pair of
+            #[doc = stringify!($t)]
+            /// , such that problematic ratios are much more common than
+            /// normal.
+            ///
+            /// Zero denominators are intentional, to exercise division by
+            /// zero. The other special ratios have a denominator of 1, equal
+            /// numerator and denominator, and both set to `MAX`. For signed
+            /// integers, `MIN` divided by -1 is also generated.
+            pub fn [<weird_ratio_ $t>]() -> ($t, $t) {
+                with_wdg(|wdg| wdg.[<weird_ratio_ $t>]())
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// , such that small magnitudes and special values are much more
+            /// common than normal.
+            ///
+            /// A bit-width `w` is picked first, then the magnitude is uniformly
+            /// random in `0..2^w`. The distribution is as follows:
+            /// - 25% "special" values, such as `MAX` and 0
+            /// - 50% a width of at most 8, i.e. a magnitude of at most 255,
+            ///   or at most 127 for `i8`
+            /// - 25% a uniformly random width, up to the number of bits of
+            ///   `MAX`
+            pub fn [<small_biased_ $t>]() -> $t {
+                with_wdg(|wdg| wdg.[<small_biased_ $t>]())
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// with a special bit pattern
+            ///
+            /// The bit patterns are alternating bits like `0xAAAA` and
+            /// `0x5555`, powers of two (a single bit set), powers of two minus
+            /// one, a single bit clear, the sign bit only, and `MAX / 2`.
+            pub fn [<special_bits_ $t>]() -> $t {
+                with_wdg(|wdg| wdg.[<special_bits_ $t>]())
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
+            /// "special" value
+            ///
+            /// This is like
+            #[doc = concat!("[special_", stringify!($t), "]")]
+            /// , but it never generates 0.
+            pub fn [<special_nonzero_ $t>]() -> core::num::[<NonZero $t:camel>] {
+                with_wdg(|wdg| wdg.[<special_nonzero_ $t>]())
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
+            /// , such that special or problematic values are much
+            /// more common than normal.
+            pub fn [<nonzero_ $t>]() -> core::num::[<NonZero $t:camel>] {
+                with_wdg(|wdg| wdg.[<nonzero_ $t>]())
+            }
         }
     };
 }
 
 int_uint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! weird_map {
+    ($($t:ident),+ $(,)?) => {
+        paste! {
+            $(
+                #[doc = concat!("Generate a weird ", stringify!($t), " with [", stringify!($t), "()], and map it")]
+                /// to a user type with `f`.
+                pub fn [<weird_map_ $t>]<T>(f: impl FnOnce($t) -> T) -> T {
+                    f($t())
+                }
+            )+
+        }
+    };
+}
+
+weird_map!(f32, f64, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
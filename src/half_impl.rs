@@ -0,0 +1,172 @@
+//! Generators of weird half-precision floats, using the [half] crate.
+
+use half::{bf16, f16};
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Generates a random f16 `NAN` value.
+    ///
+    /// There are multiple bit patterns that are equivalent to a `NAN`.
+    /// This generator covers all possible `NAN` values as specified in
+    /// IEEE-754, even ones that Rust would normally not generate.
+    pub fn nan_f16(&mut self) -> f16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+        let exponent: u16 = 0b1_1111 << 10;
+
+        // mantissa 00...00 is INFINITY not NAN!
+        let mantissa: u16 = self.0.u16(1..(1 << 10));
+
+        let bits = sign | exponent | mantissa;
+        f16::from_bits(bits)
+    }
+
+    /// Generates a random bf16 `NAN` value.
+    ///
+    /// There are multiple bit patterns that are equivalent to a `NAN`.
+    /// This generator covers all possible `NAN` values, even ones that Rust
+    /// would normally not generate.
+    pub fn nan_bf16(&mut self) -> bf16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+        let exponent: u16 = 0b1111_1111 << 7;
+
+        // mantissa 00...00 is INFINITY not NAN!
+        let mantissa: u16 = self.0.u16(1..(1 << 7));
+
+        let bits = sign | exponent | mantissa;
+        bf16::from_bits(bits)
+    }
+
+    /// Generates a random f16 denormal value.
+    ///
+    /// This generator covers all possible denormal values as specified in
+    /// IEEE-754.
+    pub fn subnormal_f16(&mut self) -> f16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+
+        // mantissa 00...00 is zero not denormal!
+        let mantissa: u16 = self.0.u16(1..(1 << 10));
+
+        let bits = sign | mantissa;
+        f16::from_bits(bits)
+    }
+
+    /// Generates a random bf16 denormal value.
+    ///
+    /// This generator covers all possible denormal values.
+    pub fn subnormal_bf16(&mut self) -> bf16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+
+        // mantissa 00...00 is zero not denormal!
+        let mantissa: u16 = self.0.u16(1..(1 << 7));
+
+        let bits = sign | mantissa;
+        bf16::from_bits(bits)
+    }
+
+    /// Generate a random f16 normal value
+    pub fn normal_f16(&mut self) -> f16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+
+        // careful with this range, all zeros and all ones are not normal
+        let exponent: u16 = self.0.u16(0b0_0001..=0b1_1110) << 10;
+
+        let mantissa: u16 = self.0.u16(0..(1 << 10));
+        let bits = sign | exponent | mantissa;
+        f16::from_bits(bits)
+    }
+
+    /// Generate a random bf16 normal value
+    pub fn normal_bf16(&mut self) -> bf16 {
+        let sign: u16 = self.0.u16(0..=1) << 15;
+
+        // careful with this range, all zeros and all ones are not normal
+        let exponent: u16 = self.0.u16(0b0000_0001..=0b1111_1110) << 7;
+
+        let mantissa: u16 = self.0.u16(0..(1 << 7));
+        let bits = sign | exponent | mantissa;
+        bf16::from_bits(bits)
+    }
+
+    /// Generate a random f16 "special" value
+    ///
+    /// A special value is what I call specific float values that are unique and
+    /// are pretty much impossible to generate by chance, and have some unusual
+    /// properties.
+    pub fn special_f16(&mut self) -> f16 {
+        match self.0.u8(0..=11) {
+            0 => f16::ZERO,
+            1 => f16::NEG_ZERO,
+            2 => f16::INFINITY,
+            3 => f16::NEG_INFINITY,
+            4 => f16::ONE,
+            5 => f16::NEG_ONE,
+            6 => f16::MIN,
+            7 => f16::MAX,
+            8 => f16::MIN_POSITIVE,
+            9 => -f16::MIN_POSITIVE,
+            10 => f16::EPSILON,
+            11 => -f16::EPSILON,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random bf16 "special" value
+    ///
+    /// A special value is what I call specific float values that are unique and
+    /// are pretty much impossible to generate by chance, and have some unusual
+    /// properties.
+    pub fn special_bf16(&mut self) -> bf16 {
+        match self.0.u8(0..=11) {
+            0 => bf16::ZERO,
+            1 => bf16::NEG_ZERO,
+            2 => bf16::INFINITY,
+            3 => bf16::NEG_INFINITY,
+            4 => bf16::ONE,
+            5 => bf16::NEG_ONE,
+            6 => bf16::MIN,
+            7 => bf16::MAX,
+            8 => bf16::MIN_POSITIVE,
+            9 => -bf16::MIN_POSITIVE,
+            10 => bf16::EPSILON,
+            11 => -bf16::EPSILON,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random f16, such that special or problematic values are much
+    /// more common than normal.
+    ///
+    /// The distribution is as follows:
+    /// - 25% normal values
+    /// - 25% subnormal values
+    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
+    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
+    pub fn f16(&mut self) -> f16 {
+        match self.0.u8(0..4) {
+            0 => self.normal_f16(),
+            1 => self.subnormal_f16(),
+            2 => self.nan_f16(),
+            3 => self.special_f16(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random bf16, such that special or problematic values are much
+    /// more common than normal.
+    ///
+    /// The distribution is as follows:
+    /// - 25% normal values
+    /// - 25% subnormal values
+    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
+    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
+    pub fn bf16(&mut self) -> bf16 {
+        match self.0.u8(0..4) {
+            0 => self.normal_bf16(),
+            1 => self.subnormal_bf16(),
+            2 => self.nan_bf16(),
+            3 => self.special_bf16(),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Generators writing weird data to `std::io` streams.
+
+use std::io::{self, Write};
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Write `n` weird bytes to `writer`, generated like with
+    /// [Wdg::fill_weird_bytes].
+    ///
+    /// The bytes are streamed in chunks of 4 KiB through a buffer on the
+    /// stack, so the memory used is constant, no matter how large `n` is.
+    /// This is useful for writing large corpus files for external fuzzers.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let mut out = Vec::new();
+    /// gen.write_weird_bytes(&mut out, 10_000).unwrap();
+    /// assert_eq!(out.len(), 10_000);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `writer`, in which case an
+    /// unknown number of bytes may have already been written.
+    pub fn write_weird_bytes(&mut self, writer: &mut impl Write, n: usize) -> io::Result<()> {
+        let mut buf = [0; 4096];
+        let mut remaining = n;
+        while remaining > 0 {
+            let chunk = &mut buf[..remaining.min(4096)];
+            self.fill_weird_bytes(chunk);
+            writer.write_all(chunk)?;
+            remaining -= chunk.len();
+        }
+        Ok(())
+    }
+}
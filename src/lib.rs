@@ -1,11 +1,11 @@
 //! Generate random data in such a way as to make rare edge-cases very likely.
 //!
 //! > Disclaimer: the random number generators used in this crate are NOT
-//! CRYPTOGRAPHICALLY SECURE. Only use these generators for generating testing
-//! inputs, do not rely on them for cryptographic purposes in production code!
-//! For instance, you may test a cryptographic tool with these generators, but
-//! you may not deploy code that relies on these generators for security in
-//! production.
+//! > CRYPTOGRAPHICALLY SECURE. Only use these generators for generating testing
+//! > inputs, do not rely on them for cryptographic purposes in production code!
+//! > For instance, you may test a cryptographic tool with these generators, but
+//! > you may not deploy code that relies on these generators for security in
+//! > production.
 //!
 //! For instance, if generating a random `f32` by uniformly sampling 32 bits of
 //! data, certain values will rarely appear, such as `NAN` and `INFINITY`. When
@@ -20,30 +20,247 @@
 //! This crate is based on the [fastrand]() crate.
 //!
 //! This crate can work with `no_std`, if you disable the `std` feature. You
-//! cannot use the global functions when in a `no_std` environment. In that
-//! case, you can explicitly instantiate [Wdg] and call the methods on it.
-//! They are equivalent.
+//! cannot use the global functions when in a `no_std` environment, unless the
+//! `no-std-global` feature is enabled. Otherwise, you can explicitly
+//! instantiate [Wdg] and call the methods on it. They are equivalent. To seed
+//! it from a platform specific entropy source, like a hardware random number
+//! generator, use [Wdg::with_entropy].
+//!
+//! With `no-std-global` and without `std`, the global generator is behind a
+//! spinlock. It starts from a fixed seed, as there is no system entropy, so
+//! call [seed] first if you need different values on every run. The
+//! spinlock is only safe to use from threads or a single core: if an
+//! interrupt handler uses the global functions while the code it
+//! interrupted holds the lock, it deadlocks.
+//!
+//! Generators that allocate, such as [Wdg::weird_string], require the `alloc`
+//! feature, which is enabled by `std`.
 //!
 //! If using `std`, it's more ergonomic to use the global functions in the
 //! [global_functions] module.
+//! The global generator is thread-local, unless the `global-sync` feature is
+//! enabled, in which case a single generator is shared by all threads.
+//!
+//! With the `derive` feature, the [Weird] trait can be derived for structs and
+//! enums made of weird fields.
+//!
+//! With the `half` feature, the half-precision `f16` and `bf16` types of the
+//! `half` crate are also supported.
+//!
+//! With the `rand_core` feature, [Wdg] implements `rand_core::RngCore` and
+//! `rand_core::SeedableRng`. Beware that these traits give access to the raw
+//! uniform entropy of the generator, not to the weird-biased values.
+//!
+//! With the `arbitrary` feature, a [Wdg] can be seeded from the byte stream
+//! of a fuzzer, such as `cargo fuzz`, with `Wdg::from_unstructured`.
+//!
+//! With the `serde` feature, [Wdg] implements `Serialize` and `Deserialize`.
+//! Only the current state of the generator is serialized, as a single `u64`,
+//! not its configuration: the float weights set with [WdgBuilder] are not
+//! captured, and must be re-applied after deserializing.
+//!
+//! With the `proptest` feature, the `strategies` module provides proptest
+//! strategies whose initial values are weird.
+//!
+//! With the `quickcheck` feature, wrappers like `WeirdF32` implement
+//! `quickcheck::Arbitrary` by generating weird values.
+//!
+//! With the `chrono` feature, weird `NaiveDate` and `NaiveDateTime` values
+//! can be generated, such as leap days and daylight saving time transitions.
+//!
+//! The [float_utils] module contains helpers for inspecting generated floats,
+//! such as bit-exact comparison and telling quiet and signaling `NAN` apart.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use fastrand as fr;
 use paste::paste;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "no-std-global"))]
 mod global_functions;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "no-std-global"))]
 pub use global_functions::*;
 
-#[cfg(test)]
-mod float_utils;
+pub mod float_utils;
+
+mod combinators;
+mod slice;
+mod weights;
+mod weird;
+
+#[cfg(feature = "alloc")]
+mod blob;
+#[cfg(feature = "alloc")]
+mod matrix;
+#[cfg(feature = "alloc")]
+mod replay;
+#[cfg(feature = "alloc")]
+mod text;
+
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+mod net;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "std")]
+mod time;
+
+#[cfg(feature = "rand_core")]
+mod rand_core_impl;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
+#[cfg(feature = "half")]
+mod half_impl;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+
+#[cfg(feature = "alloc")]
+pub use blob::Endian;
+#[cfg(feature = "alloc")]
+pub use matrix::MatrixMode;
+#[cfg(feature = "alloc")]
+pub use replay::{Recordable, Recording, ReplayEntry};
+use weights::FloatCategory;
+pub use weights::{FloatCategoryCounts, FloatWeights, WdgBuilder};
+pub use weird::Weird;
+
+#[doc(hidden)]
+pub use weird::__private;
+
+#[cfg(feature = "derive")]
+pub use weird_data_derive::Weird;
+
+#[cfg(feature = "quickcheck")]
+pub use quickcheck_impl::*;
 
 /// A weird data generator
+///
+/// Besides the state of the random number generator, this holds the weights
+/// of the float categories used by [Wdg::f32] and [Wdg::f64], which can be
+/// configured with [Wdg::builder].
 #[derive(Clone)]
-pub struct Wdg(fr::Rng);
+pub struct Wdg(fr::Rng, FloatWeights);
+
+impl From<u64> for Wdg {
+    /// Same as [Wdg::with_seed].
+    fn from(seed: u64) -> Self {
+        Self::with_seed(seed)
+    }
+}
+
+impl From<[u8; 8]> for Wdg {
+    /// Same as [Wdg::from_bytes], the bytes are read as a little-endian seed.
+    fn from(bytes: [u8; 8]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl core::fmt::Debug for Wdg {
+    /// Shows the current seed, for instance `Wdg { seed: 0x0123456789abcdef }`.
+    ///
+    /// This does not advance the generator, so a generator created with
+    /// [Wdg::with_seed] from the printed seed produces the same values as this
+    /// one from this point on. If the float weights were configured with
+    /// [Wdg::builder], they are shown as well.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("Wdg");
+        debug.field("seed", &format_args!("{:#018x}", self.get_seed()));
+        if self.1 != FloatWeights::default() {
+            debug.field("weights", &self.1);
+        }
+        debug.finish()
+    }
+}
+
+/// The 64 bit FNV-1a hash, used by [Wdg::seeded_by].
+///
+/// Integers are always hashed as little-endian bytes, and `usize` and
+/// `isize` as 64 bit integers, so that the hash is the same on every
+/// platform.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl core::hash::Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as i64 as u64);
+    }
+}
+
+/// The SplitMix64 finalizer, a bijective mixing function on `u64`.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
 
 macro_rules! int {
     ($self:tt, [$($t:ty),+ $(,)?]) => {
@@ -64,25 +281,217 @@ macro_rules! int_inner {
             /// are pretty much impossible to generate by chance, and have some unusual
             /// properties. For instance `MAX` and 0.
             pub fn [<special_ $t>](&mut $self) -> $t {
-                match $self.0.u8(0..5) {
-                    0 => 0,
-                    1 => 1,
-                    2 => $t::MAX,
+                Self::[<SPECIAL_ $t:upper>][$self.0.u8(0..5) as usize]
+            }
+
+            const [<SPECIAL_ $t:upper>]: [$t; 5] = [0, 1, $t::MAX, -1, $t::MIN];
+
+            /// Iterate over all the
+            #[doc = stringify!($t)]
+            /// "special" values, see
+            #[doc = concat!("[Wdg::special_", stringify!($t), "]")]
+            /// .
+            ///
+            /// Every value is yielded exactly once, in a fixed order, without
+            /// using any randomness.
+            pub fn [<special_ $t _values>]() -> impl Iterator<Item = $t> {
+                Self::[<SPECIAL_ $t:upper>].into_iter()
+            }
+
+            /// Collect all the
+            #[doc = stringify!($t)]
+            /// "special" values in a `Vec`, see
+            #[doc = concat!("[Wdg::special_", stringify!($t), "_values]")]
+            /// .
+            ///
+            /// This is useful for explicitly checking every known problematic
+            /// value, for instance in snapshot tests.
+            #[cfg(feature = "alloc")]
+            pub fn [<all_special_ $t>]() -> alloc::vec::Vec<$t> {
+                Self::[<SPECIAL_ $t:upper>].to_vec()
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// , such that special or problematic values are much
+            /// more common than normal.
+            pub fn $t(&mut $self) -> $t {
+                match $self.0.u8(0..4) {
+                    0 => $self.[<special_ $t>](),
+                    1 => $self.[<special_bits_ $t>](),
+                    2 => $self.0.$t(2..$t::MAX),
+                    3 => $self.0.$t($t::MIN..-1),
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// at or right next to the boundaries of the type
+            ///
+            /// The values are `MIN`, `MIN + 1`, `MIN + 2`, -1, 0, 1,
+            /// `MAX - 2`, `MAX - 1` and `MAX`.
+            pub fn [<near_boundary_ $t>](&mut $self) -> $t {
+                match $self.0.u8(0..9) {
+                    0 => $t::MIN,
+                    1 => $t::MIN + 1,
+                    2 => $t::MIN + 2,
                     3 => -1,
-                    4 => $t::MIN,
+                    4 => 0,
+                    5 => 1,
+                    6 => $t::MAX - 2,
+                    7 => $t::MAX - 1,
+                    8 => $t::MAX,
                     _ => unreachable!(),
                 }
             }
 
             /// Generate a random
             #[doc = stringify!($t)]
+            /// close to `target`
+            ///
+            /// The value is `target` plus or minus a small delta of at most 3,
+            /// saturating at the bounds of the type.
+            pub fn [<near_value_ $t>](&mut $self, target: $t) -> $t {
+                let delta = $self.0.u8(0..=3) as $t;
+                if $self.0.bool() {
+                    target.saturating_add(delta)
+                } else {
+                    target.saturating_sub(delta)
+                }
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose sum overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like `MAX` and 1, or `MAX - 1` and 2.
+            /// Both positive and negative overflows are generated.
+            pub fn [<overflowing_add_pair_ $t>](&mut $self) -> ($t, $t) {
+                let (a, b) = if $self.0.bool() {
+                    let a = $self.[<edge_biased_ $t>]($t::MAX, 1);
+                    (a, $self.[<edge_biased_ $t>]($t::MAX - a + 1, $t::MAX))
+                } else {
+                    let a = $self.[<edge_biased_ $t>]($t::MIN, -1);
+                    (a, $self.[<edge_biased_ $t>]($t::MIN - a - 1, $t::MIN))
+                };
+                if $self.0.bool() { (a, b) } else { (b, a) }
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose difference overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like 0 and `MIN`, or -2 and `MAX`. Both positive and
+            /// negative overflows are generated.
+            pub fn [<overflowing_sub_pair_ $t>](&mut $self) -> ($t, $t) {
+                if $self.0.bool() {
+                    let a = $self.[<edge_biased_ $t>](0, $t::MAX);
+                    (a, $self.[<edge_biased_ $t>](a - $t::MAX - 1, $t::MIN))
+                } else {
+                    let a = $self.[<edge_biased_ $t>](-2, $t::MIN);
+                    (a, $self.[<edge_biased_ $t>](a - $t::MIN + 1, $t::MAX))
+                }
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose product overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like 2 and `MAX / 2 + 1`, or `MIN` and -1. Both
+            /// positive and negative overflows are generated.
+            pub fn [<overflowing_mul_pair_ $t>](&mut $self) -> ($t, $t) {
+                let (a, b) = match $self.0.u8(0..4) {
+                    0 => ($t::MIN, -1),
+                    1 => {
+                        let a = $self.[<edge_biased_ $t>](2, $t::MAX);
+                        (a, $self.[<edge_biased_ $t>]($t::MAX / a + 1, $t::MAX))
+                    }
+                    2 => {
+                        let a = $self.[<edge_biased_ $t>](-2, $t::MIN);
+                        (a, $self.[<edge_biased_ $t>]($t::MAX / a - 1, $t::MIN))
+                    }
+                    3 => {
+                        let a = $self.[<edge_biased_ $t>](2, $t::MAX);
+                        (a, $self.[<edge_biased_ $t>]($t::MIN / a - 1, $t::MIN))
+                    }
+                    _ => unreachable!(),
+                };
+                if $self.0.bool() { (a, b) } else { (b, a) }
+            }
+
+            /// Generate a random numerator and denominator pair of
+            #[doc = stringify!($t)]
+            /// , such that problematic ratios are much more common than
+            /// normal.
+            ///
+            /// Zero denominators are intentional, to exercise division by
+            /// zero. The other special ratios have a denominator of 1, equal
+            /// numerator and denominator, and both set to `MAX`, as well as `MIN`
+            /// divided by -1, which overflows.
+            pub fn [<weird_ratio_ $t>](&mut $self) -> ($t, $t) {
+                match $self.0.u8(0..6) {
+                    0 => ($self.$t(), 0),
+                    1 => ($self.$t(), 1),
+                    2 => {
+                        let x = $self.$t();
+                        (x, x)
+                    }
+                    3 => ($t::MAX, $t::MAX),
+                    4 => ($t::MIN, -1),
+                    5 => ($self.$t(), $self.$t()),
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Generate an endless iterator of random
+            #[doc = stringify!($t)]
+            /// , see
+            #[doc = concat!("[Wdg::", stringify!($t), "]")]
+            /// .
+            pub fn [<iter_ $t>](&mut $self) -> impl Iterator<Item = $t> + '_ {
+                core::iter::repeat_with(move || $self.$t())
+            }
+
+            /// Generate an array of random
+            #[doc = stringify!($t)]
+            /// , see
+            #[doc = concat!("[Wdg::", stringify!($t), "]")]
+            /// .
+            pub fn [<$t _array>]<const N: usize>(&mut $self) -> [$t; N] {
+                core::array::from_fn(|_| $self.$t())
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
+            /// "special" value
+            ///
+            /// This is like
+            #[doc = concat!("[Wdg::special_", stringify!($t), "]")]
+            /// , but it never generates 0.
+            pub fn [<special_nonzero_ $t>](&mut $self) -> core::num::[<NonZero $t:camel>] {
+                let x = match $self.0.u8(0..4) {
+                    0 => 1,
+                    1 => $t::MAX,
+                    2 => -1,
+                    3 => $t::MIN,
+                    _ => unreachable!(),
+                };
+                core::num::[<NonZero $t:camel>]::new(x).unwrap()
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
             /// , such that special or problematic values are much
             /// more common than normal.
-            pub fn $t(&mut $self) -> $t {
+            pub fn [<nonzero_ $t>](&mut $self) -> core::num::[<NonZero $t:camel>] {
                 match $self.0.u8(0..3) {
-                    0 => $self.[<special_ $t>](),
-                    1 => $self.0.$t(2..$t::MAX),
-                    2 => $self.0.$t($t::MIN..-1),
+                    0 => $self.[<special_nonzero_ $t>](),
+                    1 => core::num::[<NonZero $t:camel>]::new($self.0.$t(2..$t::MAX)).unwrap(),
+                    2 => core::num::[<NonZero $t:camel>]::new($self.0.$t($t::MIN..-1)).unwrap(),
                     _ => unreachable!(),
                 }
             }
@@ -90,37 +499,88 @@ macro_rules! int_inner {
     };
 }
 
-macro_rules! uint {
+macro_rules! bits {
     ($self:tt, [$($t:ty),+ $(,)?]) => {
         $(
-            uint_inner!($self, $t);
+            bits_inner!($self, $t);
         )+
     };
 }
 
-macro_rules! uint_inner {
+macro_rules! bits_inner {
     ($self:tt, $t:ty) => {
         paste! {
             /// Generate a random
             #[doc = stringify!($t)]
-            /// "special" value
+            /// with a special bit pattern
             ///
-            /// A special value is what I call specific values that are unique and
-            /// are pretty much impossible to generate by chance, and have some unusual
-            /// properties.
-            pub fn [<special_ $t>](&mut $self) -> $t {
-                match $self.0.u8(0..3) {
-                    0 => 0,
-                    1 => 1,
-                    2 => $t::MAX,
+            /// The bit patterns are alternating bits like `0xAAAA` and
+            /// `0x5555`, powers of two (a single bit set), powers of two minus
+            /// one, a single bit clear, the sign bit only, and `MAX / 2`.
+            pub fn [<special_bits_ $t>](&mut $self) -> $t {
+                // the patterns are built as u128 and truncated to size
+                match $self.0.u8(0..7) {
+                    0 => 0xAAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_u128 as $t,
+                    1 => 0x5555_5555_5555_5555_5555_5555_5555_5555_u128 as $t,
+                    2 => (1_u128 << $self.0.u32(0..$t::BITS)) as $t,
+                    3 => (u128::MAX >> (128 - $self.0.u32(1..=$t::BITS))) as $t,
+                    4 => !(1_u128 << $self.0.u32(0..$t::BITS)) as $t,
+                    5 => (1_u128 << ($t::BITS - 1)) as $t,
+                    6 => $t::MAX / 2,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! range {
+    ($self:tt, [$($t:ty),+ $(,)?]) => {
+        $(
+            range_inner!($self, $t);
+        )+
+    };
+}
+
+macro_rules! range_inner {
+    ($self:tt, $t:ty) => {
+        paste! {
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// in the given range, such that the edges of the range are much
+            /// more common than normal.
+            ///
+            /// The first value, the last value (`end - 1`) and the midpoint
+            /// of the range are each generated 20% of the time, the rest of
+            /// the time the value is uniformly random in the range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            pub fn [<weird_range_ $t>](&mut $self, range: core::ops::Range<$t>) -> $t {
+                assert!(range.start < range.end, "empty range: {:?}", range);
+                let first = range.start;
+                let last = range.end - 1;
+                match $self.0.u8(0..5) {
+                    0 => first,
+                    1 => last,
+                    // average rounding down, without overflowing
+                    2 => (first & last) + ((first ^ last) >> 1),
+                    3 | 4 => $self.0.$t(range),
                     _ => unreachable!(),
                 }
             }
 
-            pub fn $t(&mut $self) -> $t {
-                match $self.0.u8(0..2) {
-                    0 => $self.[<special_ $t>](),
-                    1 => $self.0.$t(2..$t::MAX),
+            /// Generate a value between `edge` and `other` inclusive, biased
+            /// towards `edge` and the values right next to it.
+            fn [<edge_biased_ $t>](&mut $self, edge: $t, other: $t) -> $t {
+                let (lo, hi) = (edge.min(other), edge.max(other));
+                let delta = $self.0.u8(0..=3) as $t;
+                match $self.0.u8(0..3) {
+                    0 => edge,
+                    1 if edge == lo => lo.saturating_add(delta).min(hi),
+                    1 => hi.saturating_sub(delta).max(lo),
+                    2 => $self.0.$t(lo..=hi),
                     _ => unreachable!(),
                 }
             }
@@ -128,594 +588,4885 @@ macro_rules! uint_inner {
     };
 }
 
-impl Wdg {
-    #[must_use]
-    pub fn with_seed(seed: u64) -> Self {
-        Self(fr::Rng::with_seed(seed))
-    }
-
-    #[must_use]
-    pub fn fork(&mut self) -> Self {
-        Self(self.0.fork())
-    }
-
-    pub fn seed(&mut self, seed: u64) {
-        self.0.seed(seed);
-    }
+macro_rules! powers {
+    ($self:tt, [$($t:ty),+ $(,)?]) => {
+        $(
+            powers_inner!($self, $t);
+        )+
+    };
+}
 
-    pub fn get_seed(&mut self) -> u64 {
-        self.0.get_seed()
-    }
+macro_rules! powers_inner {
+    ($self:tt, $t:ty) => {
+        paste! {
+            /// Generate a random positive power of two that fits in
+            #[doc = concat!("`", stringify!($t), "`")]
+            ///
+            /// The exponent is uniformly random, so the values go from `1` up to the
+            /// largest power of two not exceeding `MAX`.
+            pub fn [<power_of_two_ $t>](&mut $self) -> $t {
+                1 << $self.0.u32(0..=$t::MAX.ilog2())
+            }
 
-    /// Generates a random f32 `NAN` value.
-    ///
-    /// There are multiple bit patterns that are equivalent to a `NAN`.
-    /// This generator covers all possible `NAN` values as specified in
-    /// IEEE-754, even ones that Rust would normally not generate.
-    pub fn nan_f32(&mut self) -> f32 {
-        let sign: u32 = self.0.u32(0..=1) << 31;
-        let exponent: u32 = 0b1111_1111 << 23;
+            /// Generate a random positive power of ten that fits in
+            #[doc = concat!("`", stringify!($t), "`")]
+            ///
+            /// The exponent is uniformly random, so the values go from `1` up to the
+            /// largest power of ten not exceeding `MAX`.
+            pub fn [<power_of_ten_ $t>](&mut $self) -> $t {
+                (10 as $t).pow($self.0.u32(0..=$t::MAX.ilog10()))
+            }
+        }
+    };
+}
 
-        // mantissa 00...00 is INFINITY not NAN!
-        let mantissa: u32 = self.0.u32(1..(1 << 23));
+macro_rules! small {
+    ($self:tt, [$($t:ty),+ $(,)?]) => {
+        $(
+            small_inner!($self, $t);
+        )+
+    };
+}
 
-        let bits = sign | exponent | mantissa;
-        f32::from_bits(bits)
-    }
+macro_rules! small_inner {
+    ($self:tt, $t:ty) => {
+        paste! {
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// , such that small magnitudes and special values are much more
+            /// common than normal.
+            ///
+            /// A bit-width `w` is picked first, then the magnitude is uniformly
+            /// random in `0..2^w`, so small magnitudes are about as common as
+            /// large ones, instead of the largest ones dominating. The width
+            /// is at most the number of bits of `MAX`, which excludes the sign
+            /// bit of signed integers. The distribution is as follows:
+            /// - 25% "special" values, such as `MAX` and 0
+            /// - 50% a width of at most 8, i.e. a magnitude of at most 255,
+            ///   or at most 127 for `i8`
+            /// - 25% a uniformly random width
+            ///
+            /// For signed integers, the sign of the magnitudes is random.
+            pub fn [<small_biased_ $t>](&mut $self) -> $t {
+                const MAX_WIDTH: u32 = $t::MAX.count_ones();
+                let width = match $self.0.u8(0..4) {
+                    0 => return $self.[<special_ $t>](),
+                    1 | 2 => $self.0.u32(0..=MAX_WIDTH.min(8)),
+                    3 => $self.0.u32(0..=MAX_WIDTH),
+                    _ => unreachable!(),
+                };
+                let mask = $t::MAX.checked_shr(MAX_WIDTH - width).unwrap_or(0);
+                let magnitude = $self.0.$t(..) & mask;
+                if $t::MIN != 0 && $self.0.bool() {
+                    magnitude.wrapping_neg()
+                } else {
+                    magnitude
+                }
+            }
+        }
+    };
+}
 
-    /// Generates a random f64 `NAN` value.
-    ///
-    /// There are multiple bit patterns that are equivalent to a `NAN`.
-    /// This generator covers all possible `NAN` values as specified in
-    /// IEEE-754, even ones that Rust would normally not generate.
-    pub fn nan_f64(&mut self) -> f64 {
-        let sign: u64 = self.0.u64(0..=1) << 63;
-        let exponent: u64 = 0b0111_1111_1111 << 52;
+macro_rules! uint {
+    ($self:tt, [$($t:ty),+ $(,)?]) => {
+        $(
+            uint_inner!($self, $t);
+        )+
+    };
+}
+
+macro_rules! uint_inner {
+    ($self:tt, $t:ty) => {
+        paste! {
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// "special" value
+            ///
+            /// A special value is what I call specific values that are unique and
+            /// are pretty much impossible to generate by chance, and have some unusual
+            /// properties.
+            pub fn [<special_ $t>](&mut $self) -> $t {
+                Self::[<SPECIAL_ $t:upper>][$self.0.u8(0..3) as usize]
+            }
+
+            const [<SPECIAL_ $t:upper>]: [$t; 3] = [0, 1, $t::MAX];
+
+            /// Iterate over all the
+            #[doc = stringify!($t)]
+            /// "special" values, see
+            #[doc = concat!("[Wdg::special_", stringify!($t), "]")]
+            /// .
+            ///
+            /// Every value is yielded exactly once, in a fixed order, without
+            /// using any randomness.
+            pub fn [<special_ $t _values>]() -> impl Iterator<Item = $t> {
+                Self::[<SPECIAL_ $t:upper>].into_iter()
+            }
+
+            /// Collect all the
+            #[doc = stringify!($t)]
+            /// "special" values in a `Vec`, see
+            #[doc = concat!("[Wdg::special_", stringify!($t), "_values]")]
+            /// .
+            ///
+            /// This is useful for explicitly checking every known problematic
+            /// value, for instance in snapshot tests.
+            #[cfg(feature = "alloc")]
+            pub fn [<all_special_ $t>]() -> alloc::vec::Vec<$t> {
+                Self::[<SPECIAL_ $t:upper>].to_vec()
+            }
+
+            pub fn $t(&mut $self) -> $t {
+                match $self.0.u8(0..3) {
+                    0 => $self.[<special_ $t>](),
+                    1 => $self.[<special_bits_ $t>](),
+                    2 => $self.0.$t(2..$t::MAX),
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// at or right next to the boundaries of the type
+            ///
+            /// The values are 0, 1, 2, `MAX - 2`, `MAX - 1` and `MAX`.
+            pub fn [<near_boundary_ $t>](&mut $self) -> $t {
+                match $self.0.u8(0..6) {
+                    0 => 0,
+                    1 => 1,
+                    2 => 2,
+                    3 => $t::MAX - 2,
+                    4 => $t::MAX - 1,
+                    5 => $t::MAX,
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Generate a random
+            #[doc = stringify!($t)]
+            /// close to `target`
+            ///
+            /// The value is `target` plus or minus a small delta of at most 3,
+            /// saturating at the bounds of the type.
+            pub fn [<near_value_ $t>](&mut $self, target: $t) -> $t {
+                let delta = $self.0.u8(0..=3) as $t;
+                if $self.0.bool() {
+                    target.saturating_add(delta)
+                } else {
+                    target.saturating_sub(delta)
+                }
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose sum overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like `MAX` and 1, or `MAX - 1` and 2.
+            pub fn [<overflowing_add_pair_ $t>](&mut $self) -> ($t, $t) {
+                let a = $self.[<edge_biased_ $t>]($t::MAX, 1);
+                let b = $self.[<edge_biased_ $t>]($t::MAX - a + 1, $t::MAX);
+                if $self.0.bool() { (a, b) } else { (b, a) }
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose difference underflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// underflow, like 0 and 1, or `MAX - 1` and `MAX`.
+            pub fn [<overflowing_sub_pair_ $t>](&mut $self) -> ($t, $t) {
+                let a = $self.[<edge_biased_ $t>](0, $t::MAX - 1);
+                (a, $self.[<edge_biased_ $t>](a + 1, $t::MAX))
+            }
+
+            /// Generate a random pair of
+            #[doc = stringify!($t)]
+            /// whose product overflows
+            ///
+            /// The pairs are biased towards the ones that just barely
+            /// overflow, like 2 and `MAX / 2 + 1`, or the square root of
+            /// `MAX + 1` with itself.
+            pub fn [<overflowing_mul_pair_ $t>](&mut $self) -> ($t, $t) {
+                let a = match $self.0.u8(0..3) {
+                    0 => 1 << ($t::BITS / 2),
+                    _ => $self.[<edge_biased_ $t>](2, $t::MAX),
+                };
+                let b = $self.[<edge_biased_ $t>]($t::MAX / a + 1, $t::MAX);
+                if $self.0.bool() { (a, b) } else { (b, a) }
+            }
+
+            /// Generate a random numerator and denominator pair of
+            #[doc = stringify!($t)]
+            /// , such that problematic ratios are much more common than
+            /// normal.
+            ///
+            /// Zero denominators are intentional, to exercise division by
+            /// zero. The other special ratios have a denominator of 1, equal
+            /// numerator and denominator, and both set to `MAX`.
+            pub fn [<weird_ratio_ $t>](&mut $self) -> ($t, $t) {
+                match $self.0.u8(0..5) {
+                    0 => ($self.$t(), 0),
+                    1 => ($self.$t(), 1),
+                    2 => {
+                        let x = $self.$t();
+                        (x, x)
+                    }
+                    3 => ($t::MAX, $t::MAX),
+                    4 => ($self.$t(), $self.$t()),
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Generate an endless iterator of random
+            #[doc = stringify!($t)]
+            /// , see
+            #[doc = concat!("[Wdg::", stringify!($t), "]")]
+            /// .
+            pub fn [<iter_ $t>](&mut $self) -> impl Iterator<Item = $t> + '_ {
+                core::iter::repeat_with(move || $self.$t())
+            }
+
+            /// Generate an array of random
+            #[doc = stringify!($t)]
+            /// , see
+            #[doc = concat!("[Wdg::", stringify!($t), "]")]
+            /// .
+            pub fn [<$t _array>]<const N: usize>(&mut $self) -> [$t; N] {
+                core::array::from_fn(|_| $self.$t())
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
+            /// "special" value
+            ///
+            /// This is like
+            #[doc = concat!("[Wdg::special_", stringify!($t), "]")]
+            /// , but it never generates 0.
+            pub fn [<special_nonzero_ $t>](&mut $self) -> core::num::[<NonZero $t:camel>] {
+                let x = match $self.0.u8(0..2) {
+                    0 => 1,
+                    1 => $t::MAX,
+                    _ => unreachable!(),
+                };
+                core::num::[<NonZero $t:camel>]::new(x).unwrap()
+            }
+
+            /// Generate a random
+            #[doc = stringify!([<NonZero $t:camel>])]
+            /// , such that special or problematic values are much
+            /// more common than normal.
+            pub fn [<nonzero_ $t>](&mut $self) -> core::num::[<NonZero $t:camel>] {
+                match $self.0.u8(0..2) {
+                    0 => $self.[<special_nonzero_ $t>](),
+                    1 => core::num::[<NonZero $t:camel>]::new($self.0.$t(2..$t::MAX)).unwrap(),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+impl Wdg {
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(fr::Rng::with_seed(seed), FloatWeights::default())
+    }
+
+    /// Create a new generator, seeded with one `u64` pulled from `source`.
+    ///
+    /// This is useful without `std`, where there is no system entropy, for
+    /// seeding the generator from a hardware random number generator or
+    /// other platform specific entropy source. It is the same as calling
+    /// [Wdg::with_seed] with the value returned by `source`.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// # fn read_hardware_rng() -> u64 { 4 }
+    /// let mut gen = Wdg::with_entropy(read_hardware_rng);
+    /// ```
+    #[must_use]
+    pub fn with_entropy(mut source: impl FnMut() -> u64) -> Self {
+        Self::with_seed(source())
+    }
+
+    /// Create a new generator, seeded by hashing `key`.
+    ///
+    /// This gives a reproducible generator for a meaningful key, like the
+    /// name of a test case, instead of an opaque number. The key is hashed
+    /// with a fixed hasher, so the same key always gives the same stream, on
+    /// every platform and in every run. Note that this relies on the `Hash`
+    /// implementation of the key being stable too, which is the case for
+    /// strings and integers.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut a = Wdg::seeded_by("parse_empty_input");
+    /// let mut b = Wdg::seeded_by("parse_empty_input");
+    /// assert_eq!(a.u64(), b.u64());
+    /// ```
+    #[must_use]
+    pub fn seeded_by<H: core::hash::Hash + ?Sized>(key: &H) -> Self {
+        use core::hash::Hasher;
+
+        let mut hasher = Fnv1a::default();
+        key.hash(&mut hasher);
+        Self::with_seed(hasher.finish())
+    }
+
+    /// Create a [WdgBuilder], for configuring the categories of floats
+    /// generated by [Wdg::f32] and [Wdg::f64].
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::builder().seed(0).exclude_nan().build();
+    /// assert!(!gen.f32().is_nan());
+    /// ```
+    pub fn builder() -> WdgBuilder {
+        WdgBuilder::default()
+    }
+
+    /// The weights of the float categories used by [Wdg::f32] and
+    /// [Wdg::f64].
+    pub fn float_weights(&self) -> &FloatWeights {
+        &self.1
+    }
+
+    /// Fork a new generator from this one, the fork has the same float
+    /// weights.
+    #[must_use]
+    pub fn fork(&mut self) -> Self {
+        Self(self.0.fork(), self.1)
+    }
+
+    /// Fork `n` independent generators at once.
+    ///
+    /// This is like calling [Wdg::fork] `n` times, each fork advances the
+    /// parent so all the children are mutually independent.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn fork_n(&mut self, n: usize) -> alloc::vec::Vec<Self> {
+        (0..n).map(|_| self.fork()).collect()
+    }
+
+    /// Derive an independent child generator, identified by `id`.
+    ///
+    /// Unlike [Wdg::fork], this does not advance the parent, and it always
+    /// gives the same child for the same parent state and `id`. This is
+    /// useful for giving each of many parallel workers its own reproducible
+    /// stream, derived from a single master seed.
+    ///
+    /// Distinct ids always give distinct children.
+    #[must_use]
+    pub fn stream(&self, id: u64) -> Self {
+        let seed = splitmix64(self.get_seed().wrapping_add(splitmix64(id)));
+        Self(fr::Rng::with_seed(seed), self.1)
+    }
+
+    pub fn seed(&mut self, seed: u64) {
+        self.0.seed(seed);
+    }
+
+    /// Advance the generator as if `n` random `u64` had been drawn from the
+    /// underlying `fastrand::Rng`, for instance with `rng_mut().u64(..)`.
+    ///
+    /// This is O(1), as the state of the generator advances by a constant
+    /// for every draw. It is useful for splitting a single stream into
+    /// non-overlapping blocks, for instance worker `k` can start at
+    /// `jump(k * block_size)`. Note that most methods of [Wdg] draw more than
+    /// one `u64` per generated value, and that the number of draws may vary.
+    pub fn jump(&mut self, n: u64) {
+        // the increment of the wyrand generator used by fastrand
+        const INCREMENT: u64 = 0xA076_1D64_78BD_642F;
+        let seed = self.get_seed().wrapping_add(n.wrapping_mul(INCREMENT));
+        self.0.seed(seed);
+    }
+
+    /// Reset the generator to the given seed.
+    ///
+    /// This is the same as [Wdg::seed], it is provided for symmetry with
+    /// [Wdg::reseed_from_entropy].
+    pub fn reset_to(&mut self, seed: u64) {
+        self.seed(seed);
+    }
+
+    /// Reseed the generator from system entropy, like a freshly created
+    /// generator.
+    #[cfg(feature = "std")]
+    pub fn reseed_from_entropy(&mut self) {
+        self.0 = fr::Rng::new();
+    }
+
+    /// Gives back the _current_ seed that is being held by the generator.
+    ///
+    /// This does not advance the generator.
+    pub fn get_seed(&self) -> u64 {
+        self.0.get_seed()
+    }
+
+    /// Access the underlying `fastrand::Rng`.
+    ///
+    /// This bypasses the weirdness entirely: the methods of `fastrand::Rng`
+    /// generate plain uniform values. As they draw from the same state, the
+    /// stream stays reproducible from a single seed.
+    pub fn rng_mut(&mut self) -> &mut fr::Rng {
+        &mut self.0
+    }
+
+    /// Access the underlying `fastrand::Rng` immutably.
+    ///
+    /// See [Wdg::rng_mut].
+    pub fn as_rng(&self) -> &fr::Rng {
+        &self.0
+    }
+
+    /// Capture the current state of the generator as bytes.
+    ///
+    /// The state can be restored with [Wdg::from_bytes]. The bytes are the
+    /// current seed, in little-endian order. Only the state is captured, not
+    /// the configuration: the restored generator uses the default float
+    /// weights, so the same values as this one are only produced if this one
+    /// uses them too. The float weights set with [WdgBuilder] must be
+    /// re-applied, by building a generator with the restored seed and the
+    /// original weights.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.get_seed().to_le_bytes()
+    }
+
+    /// Restore a generator from a state captured by [Wdg::to_bytes].
+    ///
+    /// The generator uses the default float weights, see [Wdg::to_bytes].
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; 8]) -> Self {
+        Self::with_seed(u64::from_le_bytes(bytes))
+    }
+
+    /// Generates a random f32 `NAN` value.
+    ///
+    /// There are multiple bit patterns that are equivalent to a `NAN`.
+    /// This generator covers all possible `NAN` values as specified in
+    /// IEEE-754, even ones that Rust would normally not generate.
+    pub fn nan_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        let exponent: u32 = 0b1111_1111 << 23;
+
+        // mantissa 00...00 is INFINITY not NAN!
+        let mantissa: u32 = self.0.u32(1..(1 << 23));
+
+        let bits = sign | exponent | mantissa;
+        f32::from_bits(bits)
+    }
+
+    /// Generates a random f64 `NAN` value.
+    ///
+    /// There are multiple bit patterns that are equivalent to a `NAN`.
+    /// This generator covers all possible `NAN` values as specified in
+    /// IEEE-754, even ones that Rust would normally not generate.
+    pub fn nan_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        let exponent: u64 = 0b0111_1111_1111 << 52;
 
         // mantissa 00...00 is INFINITY not NAN!
         let mantissa: u64 = self.0.u64(1..(1 << 52));
 
-        let bits = sign | exponent | mantissa;
-        f64::from_bits(bits)
+        let bits = sign | exponent | mantissa;
+        f64::from_bits(bits)
+    }
+
+    /// The canonical f32 quiet `NAN`, with a positive sign and an empty
+    /// payload.
+    ///
+    /// This has the same bit pattern as `f32::NAN`, and it does not use any
+    /// randomness. Use [Wdg::nan_f32] for `NAN` values with all payloads.
+    pub fn canonical_nan_f32() -> f32 {
+        f32::from_bits(0x7FC0_0000)
+    }
+
+    /// The canonical f64 quiet `NAN`, with a positive sign and an empty
+    /// payload.
+    ///
+    /// This has the same bit pattern as `f64::NAN`, and it does not use any
+    /// randomness. Use [Wdg::nan_f64] for `NAN` values with all payloads.
+    pub fn canonical_nan_f64() -> f64 {
+        f64::from_bits(0x7FF8_0000_0000_0000)
+    }
+
+    /// Number of chunks walked by [Wdg::nan_f64_covering].
+    pub const NAN_F64_COVERING_CHUNKS: u64 = 1 << 12;
+
+    /// Generates an f64 `NAN` value, systematically covering the sign and
+    /// the top bits of the payload.
+    ///
+    /// The sign and the top 11 bits of the mantissa, including the quiet bit,
+    /// are fully determined by `chunk`, while the remaining 41 bits of the
+    /// payload are random. Calling this with every `chunk` in
+    /// `0..Wdg::NAN_F64_COVERING_CHUNKS` generates every combination of
+    /// sign and top payload bits exactly once, so a test loop can cover them
+    /// all in a bounded number of iterations. Larger values of `chunk` wrap
+    /// around.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// for chunk in 0..Wdg::NAN_F64_COVERING_CHUNKS {
+    ///     assert!(gen.nan_f64_covering(chunk).is_nan());
+    /// }
+    /// ```
+    pub fn nan_f64_covering(&mut self, chunk: u64) -> f64 {
+        let chunk = chunk % Self::NAN_F64_COVERING_CHUNKS;
+        let sign: u64 = (chunk >> 11) << 63;
+        let exponent: u64 = 0b0111_1111_1111 << 52;
+        let top: u64 = (chunk & 0b111_1111_1111) << 41;
+
+        // mantissa 00...00 is INFINITY not NAN!
+        let low: u64 = if top == 0 {
+            self.0.u64(1..(1 << 41))
+        } else {
+            self.0.u64(0..(1 << 41))
+        };
+
+        let bits = sign | exponent | top | low;
+        f64::from_bits(bits)
+    }
+
+    /// Generates an f32 `NAN` value with the given payload.
+    ///
+    /// The low 22 bits of `payload` are used as the payload, the rest is
+    /// ignored. Only the sign and the quiet bit are random. A zero payload is
+    /// replaced by 1, so that the result is always a `NAN`, even when the
+    /// quiet bit is clear.
+    pub fn nan_f32_with_payload(&mut self, payload: u32) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        let exponent: u32 = 0b1111_1111 << 23;
+        let quiet: u32 = self.0.u32(0..=1) << 22;
+
+        // payload 00...00 could be INFINITY not NAN!
+        let payload = match payload & ((1 << 22) - 1) {
+            0 => 1,
+            p => p,
+        };
+
+        let bits = sign | exponent | quiet | payload;
+        f32::from_bits(bits)
+    }
+
+    /// Generates an f64 `NAN` value with the given payload.
+    ///
+    /// The low 51 bits of `payload` are used as the payload, the rest is
+    /// ignored. Only the sign and the quiet bit are random. A zero payload is
+    /// replaced by 1, so that the result is always a `NAN`, even when the
+    /// quiet bit is clear. This is useful for testing NaN-boxing.
+    pub fn nan_f64_with_payload(&mut self, payload: u64) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        let exponent: u64 = 0b0111_1111_1111 << 52;
+        let quiet: u64 = self.0.u64(0..=1) << 51;
+
+        // payload 00...00 could be INFINITY not NAN!
+        let payload = match payload & ((1 << 51) - 1) {
+            0 => 1,
+            p => p,
+        };
+
+        let bits = sign | exponent | quiet | payload;
+        f64::from_bits(bits)
+    }
+
+    /// Generates a random f32 quiet `NAN` value.
+    ///
+    /// A quiet `NAN` has the most significant bit of the mantissa set. The
+    /// sign and the remaining payload bits are random.
+    pub fn quiet_nan_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        let exponent: u32 = 0b1111_1111 << 23;
+        let quiet: u32 = 1 << 22;
+        let payload: u32 = self.0.u32(0..(1 << 22));
+
+        let bits = sign | exponent | quiet | payload;
+        f32::from_bits(bits)
+    }
+
+    /// Generates a random f64 quiet `NAN` value.
+    ///
+    /// A quiet `NAN` has the most significant bit of the mantissa set. The
+    /// sign and the remaining payload bits are random.
+    pub fn quiet_nan_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        let exponent: u64 = 0b0111_1111_1111 << 52;
+        let quiet: u64 = 1 << 51;
+        let payload: u64 = self.0.u64(0..(1 << 51));
+
+        let bits = sign | exponent | quiet | payload;
+        f64::from_bits(bits)
+    }
+
+    /// Generates a random f32 signaling `NAN` value.
+    ///
+    /// A signaling `NAN` has the most significant bit of the mantissa clear.
+    /// The sign and the remaining payload bits are random.
+    pub fn signaling_nan_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        let exponent: u32 = 0b1111_1111 << 23;
+
+        // payload 00...00 is INFINITY not NAN!
+        let payload: u32 = self.0.u32(1..(1 << 22));
+
+        let bits = sign | exponent | payload;
+        f32::from_bits(bits)
+    }
+
+    /// Generates a random f64 signaling `NAN` value.
+    ///
+    /// A signaling `NAN` has the most significant bit of the mantissa clear.
+    /// The sign and the remaining payload bits are random.
+    pub fn signaling_nan_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        let exponent: u64 = 0b0111_1111_1111 << 52;
+
+        // payload 00...00 is INFINITY not NAN!
+        let payload: u64 = self.0.u64(1..(1 << 51));
+
+        let bits = sign | exponent | payload;
+        f64::from_bits(bits)
+    }
+
+    /// Generates a random f32 that a plain uniform float generator would
+    /// never produce.
+    ///
+    /// This only generates `-0.0` and `NAN` values other than the canonical
+    /// `f32::NAN`, which includes signaling `NAN` values, negative `NAN`
+    /// values and quiet `NAN` values with non-empty payloads. The
+    /// distribution is as follows:
+    /// - 25% `-0.0`
+    /// - 25% signaling `NAN`, see [Wdg::signaling_nan_f32]
+    /// - 50% any non-canonical `NAN`, see [Wdg::nan_f32]
+    pub fn exotic_f32(&mut self) -> f32 {
+        match self.0.u8(0..4) {
+            0 => -0.0,
+            1 => self.signaling_nan_f32(),
+            2 | 3 => loop {
+                let nan = self.nan_f32();
+                if nan.to_bits() != Self::canonical_nan_f32().to_bits() {
+                    break nan;
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generates a random f64 that a plain uniform float generator would
+    /// never produce.
+    ///
+    /// This only generates `-0.0` and `NAN` values other than the canonical
+    /// `f64::NAN`, which includes signaling `NAN` values, negative `NAN`
+    /// values and quiet `NAN` values with non-empty payloads. The
+    /// distribution is as follows:
+    /// - 25% `-0.0`
+    /// - 25% signaling `NAN`, see [Wdg::signaling_nan_f64]
+    /// - 50% any non-canonical `NAN`, see [Wdg::nan_f64]
+    pub fn exotic_f64(&mut self) -> f64 {
+        match self.0.u8(0..4) {
+            0 => -0.0,
+            1 => self.signaling_nan_f64(),
+            2 | 3 => loop {
+                let nan = self.nan_f64();
+                if nan.to_bits() != Self::canonical_nan_f64().to_bits() {
+                    break nan;
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Check whether `f` preserves signaling `NAN` values.
+    ///
+    /// A random signaling `NAN` is generated with
+    /// [Wdg::signaling_nan_f32] and passed to `f`, this returns `true` if
+    /// the output of `f` is still a signaling `NAN`. Note that most
+    /// arithmetic operations turn a signaling `NAN` into a quiet one, and so
+    /// can some hardware when merely loading or storing the value.
+    pub fn preserves_signaling_nan_f32(&mut self, f: impl FnOnce(f32) -> f32) -> bool {
+        let x = self.signaling_nan_f32();
+        debug_assert!(float_utils::f32_is_signaling_nan(x));
+        float_utils::f32_is_signaling_nan(f(x))
+    }
+
+    /// Check whether `f` preserves signaling `NAN` values.
+    ///
+    /// A random signaling `NAN` is generated with
+    /// [Wdg::signaling_nan_f64] and passed to `f`, this returns `true` if
+    /// the output of `f` is still a signaling `NAN`. Note that most
+    /// arithmetic operations turn a signaling `NAN` into a quiet one, and so
+    /// can some hardware when merely loading or storing the value.
+    pub fn preserves_signaling_nan_f64(&mut self, f: impl FnOnce(f64) -> f64) -> bool {
+        let x = self.signaling_nan_f64();
+        debug_assert!(float_utils::f64_is_signaling_nan(x));
+        float_utils::f64_is_signaling_nan(f(x))
+    }
+
+    /// Generates a random f32 denormal value.
+    ///
+    /// This generator covers all possible denormal values as specified in
+    /// IEEE-754.
+    pub fn subnormal_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+
+        // mantissa 00...00 is zero not denormal!
+        let mantissa: u32 = self.0.u32(1..(1 << 23));
+
+        let bits = sign | mantissa;
+        f32::from_bits(bits)
+    }
+
+    /// Generates a random f64 denormal value.
+    ///
+    /// This generator covers all possible denormal values as specified in
+    /// IEEE-754.
+    pub fn subnormal_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+
+        // mantissa 00...00 is zero not denormal!
+        let mantissa: u64 = self.0.u64(1..(1 << 52));
+
+        let bits = sign | mantissa;
+        f64::from_bits(bits)
+    }
+
+    /// Generates a random f32 denormal value at the boundaries of the
+    /// denormal range.
+    ///
+    /// The smallest denormal, right above zero, and the largest denormal,
+    /// right below `MIN_POSITIVE`, are much more common than normal, as are
+    /// the few denormals right next to them. The sign is random.
+    pub fn subnormal_boundary_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        const LARGEST: u32 = (1 << 23) - 1;
+
+        let mantissa: u32 = match self.0.u8(0..6) {
+            0 => 1,
+            1 => LARGEST,
+            2 => 1 + self.0.u32(1..=3),
+            3 => LARGEST - self.0.u32(1..=3),
+            4 | 5 => self.0.u32(1..=LARGEST),
+            _ => unreachable!(),
+        };
+
+        let bits = sign | mantissa;
+        f32::from_bits(bits)
+    }
+
+    /// Generates a random f64 denormal value at the boundaries of the
+    /// denormal range.
+    ///
+    /// The smallest denormal, right above zero, and the largest denormal,
+    /// right below `MIN_POSITIVE`, are much more common than normal, as are
+    /// the few denormals right next to them. The sign is random.
+    ///
+    /// ```
+    /// # use weird_data::{float_utils, Wdg};
+    /// let smallest = f64::from_bits(1);
+    /// let largest = float_utils::f64_step_ulps(f64::MIN_POSITIVE, -1);
+    ///
+    /// let mut gen = Wdg::with_seed(0);
+    /// let x = gen.subnormal_boundary_f64();
+    /// assert!(x.abs() >= smallest && x.abs() <= largest);
+    /// ```
+    pub fn subnormal_boundary_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        const LARGEST: u64 = (1 << 52) - 1;
+
+        let mantissa: u64 = match self.0.u8(0..6) {
+            0 => 1,
+            1 => LARGEST,
+            2 => 1 + self.0.u64(1..=3),
+            3 => LARGEST - self.0.u64(1..=3),
+            4 | 5 => self.0.u64(1..=LARGEST),
+            _ => unreachable!(),
+        };
+
+        let bits = sign | mantissa;
+        f64::from_bits(bits)
+    }
+
+    /// Generate a random f32 normal value
+    pub fn normal_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+
+        // careful with this range, all zeros and all ones are not normal
+        let exponent: u32 = self.0.u32(0b0000_0001..=0b1111_1110) << 23;
+
+        let mantissa: u32 = self.0.u32(0..(1 << 23));
+        let bits = sign | exponent | mantissa;
+        f32::from_bits(bits)
+    }
+
+    /// Generate a random f64 normal value
+    pub fn normal_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+
+        // careful with this range, all zeros and all ones are not normal
+        let exponent: u64 = self.0.u64(0b000_0000_0001..=0b111_1111_1110) << 52;
+
+        let mantissa: u64 = self.0.u64(0..(1 << 52));
+        let bits = sign | exponent | mantissa;
+        f64::from_bits(bits)
+    }
+
+    /// Generate a random f32 "special" value
+    ///
+    /// A special value is what I call specific float values that are unique and
+    /// are pretty much impossible to generate by chance, and have some unusual
+    /// properties.
+    pub fn special_f32(&mut self) -> f32 {
+        Self::SPECIAL_F32[self.0.u8(0..=11) as usize]
+    }
+
+    const SPECIAL_F32: [f32; 12] = [
+        0.0,
+        -0.0,
+        f32::INFINITY,
+        -f32::INFINITY,
+        1.0,
+        -1.0,
+        f32::MIN,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        f32::EPSILON,
+        -f32::EPSILON,
+    ];
+
+    /// Iterate over all the f32 "special" values, see [Wdg::special_f32].
+    ///
+    /// Every value is yielded exactly once, in a fixed order, without using
+    /// any randomness.
+    pub fn special_f32_values() -> impl Iterator<Item = f32> {
+        Self::SPECIAL_F32.into_iter()
+    }
+
+    /// Collect all the f32 "special" values in a `Vec`, see
+    /// [Wdg::special_f32_values].
+    ///
+    /// This is useful for explicitly checking every known problematic value,
+    /// for instance in snapshot tests.
+    #[cfg(feature = "alloc")]
+    pub fn all_special_f32() -> alloc::vec::Vec<f32> {
+        Self::SPECIAL_F32.to_vec()
+    }
+
+    /// Generate a random f64 "special" value
+    ///
+    /// A special value is what I call specific float values that are unique and
+    /// are pretty much impossible to generate by chance, and have some unusual
+    /// properties.
+    pub fn special_f64(&mut self) -> f64 {
+        Self::SPECIAL_F64[self.0.u8(0..=11) as usize]
+    }
+
+    const SPECIAL_F64: [f64; 12] = [
+        0.0,
+        -0.0,
+        f64::INFINITY,
+        -f64::INFINITY,
+        1.0,
+        -1.0,
+        f64::MIN,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        f64::EPSILON,
+        -f64::EPSILON,
+    ];
+
+    /// Iterate over all the f64 "special" values, see [Wdg::special_f64].
+    ///
+    /// Every value is yielded exactly once, in a fixed order, without using
+    /// any randomness.
+    pub fn special_f64_values() -> impl Iterator<Item = f64> {
+        Self::SPECIAL_F64.into_iter()
+    }
+
+    /// Collect all the f64 "special" values in a `Vec`, see
+    /// [Wdg::special_f64_values].
+    ///
+    /// This is useful for explicitly checking every known problematic value,
+    /// for instance in snapshot tests.
+    #[cfg(feature = "alloc")]
+    pub fn all_special_f64() -> alloc::vec::Vec<f64> {
+        Self::SPECIAL_F64.to_vec()
+    }
+
+    /// Generate a random f32 within a few ULPs of `x`.
+    ///
+    /// The result is `x` stepped by up to 3 ULPs in a random direction, see
+    /// [float_utils::f32_step_ulps] for how zero, the infinities and `NAN`
+    /// are handled.
+    pub fn ulp_neighbor_f32(&mut self, x: f32) -> f32 {
+        let ulps = self.0.i32(-3..=3);
+        float_utils::f32_step_ulps(x, ulps)
+    }
+
+    /// Generate a random f64 within a few ULPs of `x`.
+    ///
+    /// The result is `x` stepped by up to 3 ULPs in a random direction, see
+    /// [float_utils::f64_step_ulps] for how zero, the infinities and `NAN`
+    /// are handled.
+    pub fn ulp_neighbor_f64(&mut self, x: f64) -> f64 {
+        let ulps = self.0.i64(-3..=3);
+        float_utils::f64_step_ulps(x, ulps)
+    }
+
+    /// Generate a random f32 within a few ULPs of an "interesting" value.
+    ///
+    /// The interesting values are the powers of two, including subnormal
+    /// ones, and the "special" values of [Wdg::special_f32]. Neighbors of zero
+    /// cross over to the other sign.
+    pub fn weird_near_f32(&mut self) -> f32 {
+        let base = match self.0.bool() {
+            true => {
+                let sign: u32 = self.0.u32(0..=1) << 31;
+                let exponent: u32 = self.0.u32(0..=0b1111_1110);
+                let bits = match exponent {
+                    // subnormal powers of two have a single mantissa bit set
+                    0 => sign | 1 << self.0.u32(0..23),
+                    _ => sign | exponent << 23,
+                };
+                f32::from_bits(bits)
+            }
+            false => self.special_f32(),
+        };
+        self.ulp_neighbor_f32(base)
+    }
+
+    /// Generate a random f64 within a few ULPs of an "interesting" value.
+    ///
+    /// The interesting values are the powers of two, including subnormal
+    /// ones, and the "special" values of [Wdg::special_f64]. Neighbors of zero
+    /// cross over to the other sign.
+    pub fn weird_near_f64(&mut self) -> f64 {
+        let base = match self.0.bool() {
+            true => {
+                let sign: u64 = self.0.u64(0..=1) << 63;
+                let exponent: u64 = self.0.u64(0..=0b111_1111_1110);
+                let bits = match exponent {
+                    // subnormal powers of two have a single mantissa bit set
+                    0 => sign | 1 << self.0.u64(0..52),
+                    _ => sign | exponent << 52,
+                };
+                f64::from_bits(bits)
+            }
+            false => self.special_f64(),
+        };
+        self.ulp_neighbor_f64(base)
+    }
+
+    /// Generate a random f32, such that special or problematic values are much
+    /// more common than normal.
+    ///
+    /// The distribution is not statistically useful, but it ensures that all edge-case
+    /// values get a fair chance of being generated. This is better than using a regular
+    /// random number generator, because in the vast majority of cases, a random number
+    /// generator will generate perfectly regular and well-behaved values, and certain
+    /// values, like `INFINITY` and `NAN` may be impossible to generate.
+    ///
+    /// The distribution is as follows:
+    /// - 25% normal values
+    /// - 25% subnormal values
+    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
+    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
+    ///
+    /// Categories can be excluded with [Wdg::builder], in which case the
+    /// remaining categories are equally likely.
+    pub fn f32(&mut self) -> f32 {
+        if self.1 != FloatWeights::default() {
+            let weights = self.1;
+            return self.f32_weighted(&weights);
+        }
+        match self.0.u8(0..4) {
+            0 => self.normal_f32(),
+            1 => self.subnormal_f32(),
+            2 => self.nan_f32(),
+            3 => self.special_f32(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate the bit pattern of a random f32, with the same distribution as
+    /// [Wdg::f32].
+    ///
+    /// This draws the same values as [Wdg::f32], so the stream of a seed is the
+    /// same. The bits are returned right away, before any float operation
+    /// could quiet a signaling `NAN`, so `f32::from_bits` of the result gives
+    /// back exactly the generated value, payload included. This is useful for
+    /// writing floats into binary formats.
+    pub fn weird_f32_bits(&mut self) -> u32 {
+        self.f32().to_bits()
+    }
+
+    /// Generate the bit pattern of a random f64, with the same distribution as
+    /// [Wdg::f64].
+    ///
+    /// This draws the same values as [Wdg::f64], so the stream of a seed is the
+    /// same. The bits are returned right away, before any float operation
+    /// could quiet a signaling `NAN`, so `f64::from_bits` of the result gives
+    /// back exactly the generated value, payload included. This is useful for
+    /// writing floats into binary formats.
+    pub fn weird_f64_bits(&mut self) -> u64 {
+        self.f64().to_bits()
+    }
+
+    /// Draw `n` values with [Wdg::f32] and count how many fall in each
+    /// category.
+    ///
+    /// This is useful for checking the distribution of the generator in
+    /// your own tests.
+    pub fn sample_f32_categories(&mut self, n: usize) -> FloatCategoryCounts {
+        let mut counts = FloatCategoryCounts::default();
+        for _ in 0..n {
+            let x = self.f32();
+            if Self::SPECIAL_F32
+                .iter()
+                .any(|&s| float_utils::f32_exact_eq(s, x))
+            {
+                counts.special += 1;
+            } else if x.is_nan() {
+                counts.nan += 1;
+            } else if x.is_subnormal() {
+                counts.subnormal += 1;
+            } else {
+                counts.normal += 1;
+            }
+        }
+        counts
+    }
+
+    /// Generate a random f32, such that every class of float is guaranteed to
+    /// be covered in a bounded number of draws.
+    ///
+    /// [Wdg::f32] generates every class of float, but with no guarantee that a
+    /// given run contains, for instance, both a quiet and a signaling `NAN`.
+    /// Here, `index` is the number of the draw, and every 16 consecutive
+    /// indices generate at least one value of every
+    /// [FloatClass](float_utils::FloatClass): the first 8 indices of each
+    /// block of 16 cycle through the classes, the other 8 are the same as
+    /// [Wdg::f32].
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let values: Vec<f32> = (0..16).map(|i| gen.f32_covering(i)).collect();
+    /// assert!(values.iter().any(|x| x.is_nan()));
+    /// assert!(values.iter().any(|x| x.is_subnormal()));
+    /// ```
+    pub fn f32_covering(&mut self, index: u64) -> f32 {
+        match index % 16 {
+            0 => self.normal_f32(),
+            1 => self.subnormal_f32(),
+            2 => 0.0,
+            3 => -0.0,
+            4 => f32::INFINITY,
+            5 => f32::NEG_INFINITY,
+            6 => self.quiet_nan_f32(),
+            7 => self.signaling_nan_f32(),
+            _ => self.f32(),
+        }
+    }
+
+    /// Generate a random f64, such that special or problematic values are much
+    /// more common than normal.
+    ///
+    /// The distribution is not statistically useful, but it ensures that all edge-case
+    /// values get a fair chance of being generated. This is better than using a regular
+    /// random number generator, because in the vast majority of cases, a random number
+    /// generator will generate perfectly regular and well-behaved values, and certain
+    /// values, like `INFINITY` and `NAN` may be impossible to generate.
+    ///
+    /// The distribution is as follows:
+    /// - 25% normal values
+    /// - 25% subnormal values
+    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
+    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
+    ///
+    /// Categories can be excluded with [Wdg::builder], in which case the
+    /// remaining categories are equally likely.
+    pub fn f64(&mut self) -> f64 {
+        if self.1 != FloatWeights::default() {
+            let weights = self.1;
+            return self.f64_weighted(&weights);
+        }
+        match self.0.u8(0..4) {
+            0 => self.normal_f64(),
+            1 => self.subnormal_f64(),
+            2 => self.nan_f64(),
+            3 => self.special_f64(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random f64, such that every class of float is guaranteed to
+    /// be covered in a bounded number of draws.
+    ///
+    /// [Wdg::f64] generates every class of float, but with no guarantee that a
+    /// given run contains, for instance, both a quiet and a signaling `NAN`.
+    /// Here, `index` is the number of the draw, and every 16 consecutive
+    /// indices generate at least one value of every
+    /// [FloatClass](float_utils::FloatClass): the first 8 indices of each
+    /// block of 16 cycle through the classes, the other 8 are the same as
+    /// [Wdg::f64].
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let values: Vec<f64> = (0..16).map(|i| gen.f64_covering(i)).collect();
+    /// assert!(values.iter().any(|x| x.is_nan()));
+    /// assert!(values.iter().any(|x| x.is_subnormal()));
+    /// ```
+    pub fn f64_covering(&mut self, index: u64) -> f64 {
+        match index % 16 {
+            0 => self.normal_f64(),
+            1 => self.subnormal_f64(),
+            2 => 0.0,
+            3 => -0.0,
+            4 => f64::INFINITY,
+            5 => f64::NEG_INFINITY,
+            6 => self.quiet_nan_f64(),
+            7 => self.signaling_nan_f64(),
+            _ => self.f64(),
+        }
+    }
+
+    /// Generate a random f32, picking the category of the value proportionally
+    /// to the given weights.
+    ///
+    /// See [FloatWeights] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all weights are zero.
+    pub fn f32_weighted(&mut self, weights: &FloatWeights) -> f32 {
+        match weights.pick(&mut self.0) {
+            FloatCategory::Normal => self.normal_f32(),
+            FloatCategory::Subnormal => self.subnormal_f32(),
+            FloatCategory::Nan => self.nan_f32(),
+            FloatCategory::Special => self.special_f32(),
+        }
+    }
+
+    /// Generate a random f64, picking the category of the value proportionally
+    /// to the given weights.
+    ///
+    /// See [FloatWeights] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all weights are zero.
+    pub fn f64_weighted(&mut self, weights: &FloatWeights) -> f64 {
+        match weights.pick(&mut self.0) {
+            FloatCategory::Normal => self.normal_f64(),
+            FloatCategory::Subnormal => self.subnormal_f64(),
+            FloatCategory::Nan => self.nan_f64(),
+            FloatCategory::Special => self.special_f64(),
+        }
+    }
+
+    /// Generate an endless iterator of random f32, see [Wdg::f32].
+    pub fn iter_f32(&mut self) -> impl Iterator<Item = f32> + '_ {
+        core::iter::repeat_with(move || self.f32())
+    }
+
+    /// Generate an endless iterator of random f64, see [Wdg::f64].
+    pub fn iter_f64(&mut self) -> impl Iterator<Item = f64> + '_ {
+        core::iter::repeat_with(move || self.f64())
+    }
+
+    /// Generate an array of random f32, see [Wdg::f32].
+    ///
+    /// The array is built on the stack, without allocating.
+    pub fn f32_array<const N: usize>(&mut self) -> [f32; N] {
+        core::array::from_fn(|_| self.f32())
+    }
+
+    /// Generate an array of random f64, see [Wdg::f64].
+    ///
+    /// The array is built on the stack, without allocating.
+    pub fn f64_array<const N: usize>(&mut self) -> [f64; N] {
+        core::array::from_fn(|_| self.f64())
+    }
+
+    /// Generate a random finite f32, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// This is like [Wdg::f32], but it never generates `NAN` or infinities.
+    ///
+    /// The distribution is as follows:
+    /// - 33% normal values
+    /// - 33% subnormal values
+    /// - 33% finite "special" values, such as `-0.0` and `MAX`
+    pub fn finite_f32(&mut self) -> f32 {
+        match self.0.u8(0..3) {
+            0 => self.normal_f32(),
+            1 => self.subnormal_f32(),
+            2 => match self.0.u8(0..10) {
+                0 => 0.0,
+                1 => -0.0,
+                2 => 1.0,
+                3 => -1.0,
+                4 => f32::MIN,
+                5 => f32::MAX,
+                6 => f32::MIN_POSITIVE,
+                7 => -f32::MIN_POSITIVE,
+                8 => f32::EPSILON,
+                9 => -f32::EPSILON,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random finite f64, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// This is like [Wdg::f64], but it never generates `NAN` or infinities.
+    ///
+    /// The distribution is as follows:
+    /// - 33% normal values
+    /// - 33% subnormal values
+    /// - 33% finite "special" values, such as `-0.0` and `MAX`
+    pub fn finite_f64(&mut self) -> f64 {
+        match self.0.u8(0..3) {
+            0 => self.normal_f64(),
+            1 => self.subnormal_f64(),
+            2 => match self.0.u8(0..10) {
+                0 => 0.0,
+                1 => -0.0,
+                2 => 1.0,
+                3 => -1.0,
+                4 => f64::MIN,
+                5 => f64::MAX,
+                6 => f64::MIN_POSITIVE,
+                7 => -f64::MIN_POSITIVE,
+                8 => f64::EPSILON,
+                9 => -f64::EPSILON,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random positive f32, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This only ever generates values `> 0.0`, so it never generates `0.0`
+    /// or `NAN`, but it does generate `INFINITY`.
+    ///
+    /// The distribution is as follows:
+    /// - 33% normal values
+    /// - 33% subnormal values
+    /// - 33% "special" values, such as `MIN_POSITIVE` and `INFINITY`
+    pub fn positive_f32(&mut self) -> f32 {
+        match self.0.u8(0..3) {
+            0 => self.normal_f32().abs(),
+            1 => self.subnormal_f32().abs(),
+            2 => match self.0.u8(0..5) {
+                0 => 1.0,
+                1 => f32::MAX,
+                2 => f32::MIN_POSITIVE,
+                3 => f32::EPSILON,
+                4 => f32::INFINITY,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random non-negative f32, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is like [Wdg::positive_f32], but it also generates `0.0`. It never
+    /// generates `-0.0` or `NAN`.
+    pub fn non_negative_f32(&mut self) -> f32 {
+        match self.0.u8(0..8) {
+            0 => 0.0,
+            _ => self.positive_f32(),
+        }
+    }
+
+    /// Generate a random negative f32, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is the mirror image of [Wdg::positive_f32], it only ever generates
+    /// values `< 0.0`, including `NEG_INFINITY`.
+    pub fn negative_f32(&mut self) -> f32 {
+        -self.positive_f32()
+    }
+
+    /// Generate a random non-positive f32, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is the mirror image of [Wdg::non_negative_f32], it also generates
+    /// `-0.0`, but never `0.0` or `NAN`.
+    pub fn non_positive_f32(&mut self) -> f32 {
+        -self.non_negative_f32()
+    }
+
+    /// Generate a random positive f64, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This only ever generates values `> 0.0`, so it never generates `0.0`
+    /// or `NAN`, but it does generate `INFINITY`.
+    ///
+    /// The distribution is as follows:
+    /// - 33% normal values
+    /// - 33% subnormal values
+    /// - 33% "special" values, such as `MIN_POSITIVE` and `INFINITY`
+    pub fn positive_f64(&mut self) -> f64 {
+        match self.0.u8(0..3) {
+            0 => self.normal_f64().abs(),
+            1 => self.subnormal_f64().abs(),
+            2 => match self.0.u8(0..5) {
+                0 => 1.0,
+                1 => f64::MAX,
+                2 => f64::MIN_POSITIVE,
+                3 => f64::EPSILON,
+                4 => f64::INFINITY,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random non-negative f64, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is like [Wdg::positive_f64], but it also generates `0.0`. It never
+    /// generates `-0.0` or `NAN`.
+    pub fn non_negative_f64(&mut self) -> f64 {
+        match self.0.u8(0..8) {
+            0 => 0.0,
+            _ => self.positive_f64(),
+        }
+    }
+
+    /// Generate a random negative f64, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is the mirror image of [Wdg::positive_f64], it only ever generates
+    /// values `< 0.0`, including `NEG_INFINITY`.
+    pub fn negative_f64(&mut self) -> f64 {
+        -self.positive_f64()
+    }
+
+    /// Generate a random non-positive f64, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// This is the mirror image of [Wdg::non_negative_f64], it also generates
+    /// `-0.0`, but never `0.0` or `NAN`.
+    pub fn non_positive_f64(&mut self) -> f64 {
+        -self.non_negative_f64()
+    }
+
+    /// Generate a random sign for an f32, either `1.0` or `-1.0`, with equal
+    /// probability.
+    ///
+    /// Multiply a magnitude by this to give it a random sign.
+    pub fn sign_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        f32::from_bits(1.0f32.to_bits() | sign)
+    }
+
+    /// Generate a random signed zero f32, either `0.0` or `-0.0`, with equal
+    /// probability.
+    ///
+    /// The sign bit is set directly, as some arithmetic constructions of
+    /// `-0.0`, like `0.0 - 0.0`, actually give `0.0`.
+    pub fn signed_zero_f32(&mut self) -> f32 {
+        let sign: u32 = self.0.u32(0..=1) << 31;
+        f32::from_bits(sign)
+    }
+
+    /// Generate a random sign for an f64, either `1.0` or `-1.0`, with equal
+    /// probability.
+    ///
+    /// Multiply a magnitude by this to give it a random sign.
+    pub fn sign_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        f64::from_bits(1.0f64.to_bits() | sign)
+    }
+
+    /// Generate a random signed zero f64, either `0.0` or `-0.0`, with equal
+    /// probability.
+    ///
+    /// The sign bit is set directly, as some arithmetic constructions of
+    /// `-0.0`, like `0.0 - 0.0`, actually give `0.0`.
+    pub fn signed_zero_f64(&mut self) -> f64 {
+        let sign: u64 = self.0.u64(0..=1) << 63;
+        f64::from_bits(sign)
+    }
+
+    /// Generate a random finite f32 in `lo..=hi`, such that special or
+    /// problematic values are much more common than normal.
+    ///
+    /// The bounds, their neighbors inside the range, `0.0` and subnormal
+    /// values are much more common than normal, as long as they are in the
+    /// range. The bounds can be given in any order, and the result never
+    /// falls outside of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either bound is not finite.
+    pub fn weird_f32_in(&mut self, lo: f32, hi: f32) -> f32 {
+        assert!(
+            lo.is_finite() && hi.is_finite(),
+            "bounds must be finite: {lo:?}, {hi:?}"
+        );
+        let (lo, hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+
+        // 0.0 and subnormals are redrawn when they are out of range, instead
+        // of being clamped, which would make the bounds even more common
+        let x = loop {
+            break match self.0.u8(0..7) {
+                0 => lo,
+                1 => hi,
+                2 => float_utils::f32_step_ulps(lo, 1),
+                3 => float_utils::f32_step_ulps(hi, -1),
+                4 if lo <= 0.0 && 0.0 <= hi => 0.0,
+                5 => {
+                    let x = self.subnormal_f32();
+                    if lo <= x && x <= hi {
+                        x
+                    } else if lo <= -x && -x <= hi {
+                        -x
+                    } else {
+                        continue;
+                    }
+                }
+                6 => {
+                    // interpolate instead of using `hi - lo`, which may overflow
+                    let t = self.0.f32();
+                    lo * (1.0 - t) + hi * t
+                }
+                _ => continue,
+            };
+        };
+        x.clamp(lo, hi)
+    }
+
+    /// Generate a random finite f64 in `lo..=hi`, such that special or
+    /// problematic values are much more common than normal.
+    ///
+    /// The bounds, their neighbors inside the range, `0.0` and subnormal
+    /// values are much more common than normal, as long as they are in the
+    /// range. The bounds can be given in any order, and the result never
+    /// falls outside of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either bound is not finite.
+    pub fn weird_f64_in(&mut self, lo: f64, hi: f64) -> f64 {
+        assert!(
+            lo.is_finite() && hi.is_finite(),
+            "bounds must be finite: {lo:?}, {hi:?}"
+        );
+        let (lo, hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+
+        // 0.0 and subnormals are redrawn when they are out of range, instead
+        // of being clamped, which would make the bounds even more common
+        let x = loop {
+            break match self.0.u8(0..7) {
+                0 => lo,
+                1 => hi,
+                2 => float_utils::f64_step_ulps(lo, 1),
+                3 => float_utils::f64_step_ulps(hi, -1),
+                4 if lo <= 0.0 && 0.0 <= hi => 0.0,
+                5 => {
+                    let x = self.subnormal_f64();
+                    if lo <= x && x <= hi {
+                        x
+                    } else if lo <= -x && -x <= hi {
+                        -x
+                    } else {
+                        continue;
+                    }
+                }
+                6 => {
+                    // interpolate instead of using `hi - lo`, which may overflow
+                    let t = self.0.f64();
+                    lo * (1.0 - t) + hi * t
+                }
+                _ => continue,
+            };
+        };
+        x.clamp(lo, hi)
+    }
+
+    /// Generate a sequence of `n` f32 values that maximizes the error of
+    /// naive summation.
+    ///
+    /// The sequences are made of a big value with an even mantissa, and many
+    /// tiny terms of exactly half of its ULP, or slightly less. The big value
+    /// is never a power of two, so its ULP is the same above and below it.
+    /// When a tiny term of either sign is added to the big value, it rounds
+    /// away, ties going to the even big value, so a naive left to right sum
+    /// loses all of them, while compensated summation, like Kahan's, keeps
+    /// them. The sequences are one of:
+    /// - the big value, the tiny terms, then the negated big value, so that
+    ///   the naive sum is exactly 0 while the true sum is the sum of the tiny
+    ///   terms
+    /// - the same, but the tiny terms have random signs, so that they
+    ///   partially cancel each other
+    /// - repeated blocks of the big value, a tiny term, and the negated big
+    ///   value
+    ///
+    /// This is useful for differential testing of summation algorithms.
+    #[cfg(feature = "alloc")]
+    pub fn weird_f32_accumulation_sequence(&mut self, n: usize) -> alloc::vec::Vec<f32> {
+        // an even mantissa, so that ties round to it, but not zero, so that
+        // it is not a power of two, which has a smaller ULP below it
+        let exponent = self.0.u32(24..=120);
+        let mantissa = self.0.u32(1..1 << 22) << 1;
+        let big = f32::from_bits((exponent + 127) << 23 | mantissa);
+        let half_ulp = f32::from_bits((exponent + 127 - 24) << 23);
+        let tiny = float_utils::f32_step_ulps(half_ulp, -self.0.i32(0..=3));
+
+        let mut values = alloc::vec::Vec::with_capacity(n + 1);
+        match self.0.u8(0..3) {
+            0 => {
+                values.push(big);
+                values.resize(n.saturating_sub(1).max(1), tiny);
+                values.push(-big);
+            }
+            1 => {
+                values.push(big);
+                for _ in 2..n {
+                    values.push(if self.0.bool() { tiny } else { -tiny });
+                }
+                values.push(-big);
+            }
+            2 => values.extend((0..n).map(|i| [big, tiny, -big][i % 3])),
+            _ => unreachable!(),
+        }
+        values.truncate(n);
+        values
+    }
+
+    /// Generate a random f64 near a boundary of float to integer
+    /// conversions, such as the ones done by `as` casts and `try_into`.
+    ///
+    /// The value is one of the following boundaries, or a value right next
+    /// to it: the boundary plus or minus `0.5`, plus or minus 1, or the next
+    /// representable value in either direction. Note that for large
+    /// boundaries, adding `0.5` or 1 may round back to the boundary itself.
+    /// The boundaries are:
+    /// - `2^24` and `-2^24`, above which not every integer fits in an f32
+    /// - `2^53` and `-2^53`, above which not every integer fits in an f64
+    /// - `i8::MIN` and `i8::MAX`, `u8::MAX`
+    /// - `i16::MIN` and `i16::MAX`, `u16::MAX`
+    /// - `i32::MIN` and `i32::MAX`, `u32::MAX`
+    /// - `i64::MIN` and `i64::MAX as f64`, which rounds up to `2^63`, out of
+    ///   the range of an i64
+    /// - `u64::MAX as f64`, which rounds up to `2^64`, out of the range of a
+    ///   u64
+    /// - `i128::MIN` and `i128::MAX as f64`, `u128::MAX as f64`
+    /// - `0.0` and `-0.0`
+    pub fn weird_f64_near_integer(&mut self) -> f64 {
+        let boundary = Self::NEAR_INTEGER_F64[self.0.usize(0..Self::NEAR_INTEGER_F64.len())];
+        match self.0.u8(0..7) {
+            0 => boundary,
+            1 => boundary + 0.5,
+            2 => boundary - 0.5,
+            3 => boundary + 1.0,
+            4 => boundary - 1.0,
+            5 => float_utils::f64_step_ulps(boundary, 1),
+            6 => float_utils::f64_step_ulps(boundary, -1),
+            _ => unreachable!(),
+        }
+    }
+
+    const NEAR_INTEGER_F64: [f64; 21] = [
+        (1u64 << 24) as f64,
+        -((1u64 << 24) as f64),
+        (1u64 << 53) as f64,
+        -((1u64 << 53) as f64),
+        i8::MIN as f64,
+        i8::MAX as f64,
+        u8::MAX as f64,
+        i16::MIN as f64,
+        i16::MAX as f64,
+        u16::MAX as f64,
+        i32::MIN as f64,
+        i32::MAX as f64,
+        u32::MAX as f64,
+        i64::MIN as f64,
+        i64::MAX as f64,
+        u64::MAX as f64,
+        i128::MIN as f64,
+        i128::MAX as f64,
+        u128::MAX as f64,
+        0.0,
+        -0.0,
+    ];
+
+    /// Generate a random f64 angle, in radians, that stresses trigonometric
+    /// functions like `sin`, `cos` and `tan`.
+    ///
+    /// A quarter of the values are the f64 nearest to `k * π/2`, for a random
+    /// integer `k` of up to 20 bits, with a random sign. These are within an
+    /// ULP of a zero of `sin` or `cos`, or of an asymptote of `tan`, so the
+    /// argument reduction must be precise to get even the sign of the result
+    /// right. Note that `k * FRAC_PI_2` would not do, as the error of
+    /// `FRAC_PI_2` grows with `k`. The rest are one of the following angles,
+    /// or the next representable value in either direction:
+    /// - `0.0` and `-0.0`, where `sin` and `tan` must preserve the sign
+    /// - `π/4` and `-π/4`, where `sin` and `cos` cross and `tan` is 1
+    /// - `π/2` and `-π/2`, the asymptotes of `tan` and zeros of `cos`; since
+    ///   `FRAC_PI_2` is not exact, `tan` is huge but finite, and its
+    ///   neighbours lie on either side of the true asymptote
+    /// - `π` and `-π`, the zeros of `sin` and `tan`, and the boundaries of
+    ///   the output range of `atan2`
+    /// - `3π/2` and `2π`, the first angles past a single period reduction
+    /// - `1e22`, the largest power of ten that is exact in an f64, a classic
+    ///   test of argument reduction
+    /// - `6381956970095103 * 2^797`, the f64 closest to a multiple of `π/2`,
+    ///   the worst case of argument reduction
+    /// - `1e300`, `-1e300`, `f64::MAX` and `-f64::MAX`, where argument
+    ///   reduction needs over a thousand bits of `π`; these are only stepped
+    ///   towards zero, so the angle is always finite
+    pub fn weird_f64_angle(&mut self) -> f64 {
+        if self.0.u8(0..4) == 0 {
+            // π/2 split into 33 bits and the rest, as in fdlibm, so that the
+            // product with the high part is exact for `k < 2^20`
+            const PIO2_HI: f64 = 1.5707963267341256;
+            const PIO2_LO: f64 = 6.077100506506192e-11;
+            let width = self.0.u32(1..=20);
+            let k = self.0.u64(1..1 << width) as f64;
+            return (k * PIO2_HI + k * PIO2_LO) * self.sign_f64();
+        }
+
+        let angle = Self::ANGLES_F64[self.0.usize(0..Self::ANGLES_F64.len())];
+        let ulps = match self.0.u8(0..3) {
+            0 => return angle,
+            1 => 1,
+            2 => -1,
+            _ => unreachable!(),
+        };
+        let x = float_utils::f64_step_ulps(angle, ulps);
+        if x.is_finite() {
+            x
+        } else {
+            // `f64::MAX` and `-f64::MAX` step outward to infinity
+            float_utils::f64_step_ulps(angle, -ulps)
+        }
+    }
+
+    const ANGLES_F64: [f64; 16] = [
+        0.0,
+        -0.0,
+        core::f64::consts::FRAC_PI_4,
+        -core::f64::consts::FRAC_PI_4,
+        core::f64::consts::FRAC_PI_2,
+        -core::f64::consts::FRAC_PI_2,
+        core::f64::consts::PI,
+        -core::f64::consts::PI,
+        3.0 * core::f64::consts::FRAC_PI_2,
+        core::f64::consts::TAU,
+        1e22,
+        5.319372648326541e255,
+        1e300,
+        -1e300,
+        f64::MAX,
+        -f64::MAX,
+    ];
+
+    /// Generate a random pair of f32 values that stress comparison and
+    /// sorting code.
+    ///
+    /// The pairs are the cases where partial order, total order and
+    /// equality disagree, and they come in random order. The distribution is
+    /// as follows:
+    /// - 25% two `NAN` values, with random signs and payloads
+    /// - 25% `-0.0` and `0.0`
+    /// - 12.5% an infinity and the finite value closest to it, like `INFINITY`
+    ///   and `MAX`
+    /// - 12.5% a `NAN` value and a random value
+    /// - 25% a random value and the value one ULP above it, see
+    ///   [Wdg::f32] and [float_utils::f32_step_ulps]
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let (a, b) = gen.weird_f32_pair_for_comparison();
+    /// let mut sorted = [a, b];
+    /// sorted.sort_by(f32::total_cmp);
+    /// ```
+    pub fn weird_f32_pair_for_comparison(&mut self) -> (f32, f32) {
+        let (a, b) = match self.0.u8(0..8) {
+            0 | 1 => (self.nan_f32(), self.nan_f32()),
+            2 | 3 => (-0.0, 0.0),
+            4 if self.0.bool() => (f32::INFINITY, f32::MAX),
+            4 => (f32::NEG_INFINITY, f32::MIN),
+            5 => (self.nan_f32(), self.f32()),
+            6 | 7 => {
+                let x = self.f32();
+                (x, float_utils::f32_step_ulps(x, 1))
+            }
+            _ => unreachable!(),
+        };
+        if self.0.bool() {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Generate a random pair of f64 values that stress comparison and
+    /// sorting code.
+    ///
+    /// The pairs are the cases where partial order, total order and
+    /// equality disagree, and they come in random order. The distribution is
+    /// as follows:
+    /// - 25% two `NAN` values, with random signs and payloads
+    /// - 25% `-0.0` and `0.0`
+    /// - 12.5% an infinity and the finite value closest to it, like `INFINITY`
+    ///   and `MAX`
+    /// - 12.5% a `NAN` value and a random value
+    /// - 25% a random value and the value one ULP above it, see
+    ///   [Wdg::f64] and [float_utils::f64_step_ulps]
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let (a, b) = gen.weird_f64_pair_for_comparison();
+    /// let mut sorted = [a, b];
+    /// sorted.sort_by(f64::total_cmp);
+    /// ```
+    pub fn weird_f64_pair_for_comparison(&mut self) -> (f64, f64) {
+        let (a, b) = match self.0.u8(0..8) {
+            0 | 1 => (self.nan_f64(), self.nan_f64()),
+            2 | 3 => (-0.0, 0.0),
+            4 if self.0.bool() => (f64::INFINITY, f64::MAX),
+            4 => (f64::NEG_INFINITY, f64::MIN),
+            5 => (self.nan_f64(), self.f64()),
+            6 | 7 => {
+                let x = self.f64();
+                (x, float_utils::f64_step_ulps(x, 1))
+            }
+            _ => unreachable!(),
+        };
+        if self.0.bool() {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Generate a random `char` "special" value
+    ///
+    /// A special value is what I call specific scalar values that are unique and
+    /// are pretty much impossible to generate by chance, and have some unusual
+    /// properties. For instance `'\0'`, the largest scalar value, the scalars
+    /// right next to the surrogate gap, the replacement character and the BOM.
+    pub fn special_char(&mut self) -> char {
+        match self.0.u8(0..6) {
+            0 => '\0',
+            1 => char::MAX,
+            2 => '\u{D7FF}',
+            3 => '\u{E000}',
+            4 => char::REPLACEMENT_CHARACTER,
+            5 => '\u{FEFF}',
+            _ => unreachable!(),
+        }
+    }
+
+    /// The first scalar of the surrogate gap, which has no valid `char`.
+    const GAP_START: u32 = 0xD800;
+
+    /// The number of scalars in the surrogate gap.
+    const GAP_LEN: u32 = 0xE000 - Self::GAP_START;
+
+    /// Generate a random `char`, such that special or problematic values are
+    /// much more common than normal.
+    ///
+    /// The non-special values are sampled uniformly from all valid Unicode
+    /// scalar values, so a surrogate is never produced.
+    pub fn char(&mut self) -> char {
+        match self.0.u8(0..2) {
+            0 => self.special_char(),
+            1 => {
+                // sample from a range with the surrogate gap cut out, then
+                // shift everything above the gap back up into place
+                let mut scalar = self.0.u32(0..(char::MAX as u32 + 1 - Self::GAP_LEN));
+                if scalar >= Self::GAP_START {
+                    scalar += Self::GAP_LEN;
+                }
+                char::from_u32(scalar).unwrap()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `char` in `lo..=hi`, such that the bounds and the
+    /// block boundaries within the range are much more common than normal.
+    ///
+    /// The block boundaries are the scalars where the UTF-8 and UTF-16
+    /// encoded lengths change, and the scalars right next to the surrogate
+    /// gap. The other values are sampled uniformly, skipping the surrogate
+    /// gap if the range straddles it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    pub fn char_in_range(&mut self, lo: char, hi: char) -> char {
+        const BOUNDARIES: [char; 8] = [
+            '\u{7F}',
+            '\u{80}',
+            '\u{7FF}',
+            '\u{800}',
+            '\u{D7FF}',
+            '\u{E000}',
+            '\u{FFFF}',
+            '\u{10000}',
+        ];
+        assert!(lo <= hi, "empty range: {:?}..={:?}", lo, hi);
+
+        let mut inner = ['\0'; BOUNDARIES.len()];
+        let mut inner_len = 0;
+        for c in BOUNDARIES {
+            if lo < c && c < hi {
+                inner[inner_len] = c;
+                inner_len += 1;
+            }
+        }
+
+        match self.0.u8(0..5) {
+            0 => lo,
+            1 => hi,
+            2 if inner_len > 0 => inner[self.0.usize(0..inner_len)],
+            _ => {
+                // same as in Wdg::char, cut out the gap if it's in the range
+                let gap = if (lo as u32) < Self::GAP_START && (hi as u32) > Self::GAP_START {
+                    Self::GAP_LEN
+                } else {
+                    0
+                };
+                let mut scalar = self.0.u32(lo as u32..=hi as u32 - gap);
+                if scalar >= Self::GAP_START {
+                    scalar += gap;
+                }
+                char::from_u32(scalar).unwrap()
+            }
+        }
+    }
+
+    /// Generate a random `char` from a random Unicode general category, such
+    /// that every major category is equally likely.
+    ///
+    /// The chars are picked from a table of representative scalars for each
+    /// of the following categories, including many outside of Latin:
+    /// letters, numbers, punctuation, symbols, combining marks, control
+    /// chars, format chars and separators. This makes sure that code
+    /// branching on char properties, like `is_alphabetic` and `is_control`,
+    /// hits every branch in few draws.
+    pub fn weird_char_category(&mut self) -> char {
+        const CATEGORIES: [&str; 8] = [
+            // letters, of all cases and scripts, including titlecase
+            "aZ\u{E9}\u{DF}\u{416}\u{3B1}\u{4E2D}\u{1C5}\u{627}",
+            // numbers, decimal digits of other scripts, letterlike and fractions
+            "09\u{663}\u{96B}\u{FF10}\u{1D7D8}\u{216B}\u{BD}\u{B2}",
+            // punctuation
+            ".!\"_\u{BF}\u{AB}\u{2014}\u{3002}\u{203D}",
+            // symbols, math, currency, modifiers and emoji
+            "$+^\u{A9}\u{20AC}\u{2211}\u{1F600}\u{2764}",
+            // combining marks, nonspacing, enclosing and spacing
+            "\u{301}\u{308}\u{20DD}\u{903}\u{FE0F}",
+            // control chars
+            "\0\t\n\r\u{1B}\u{7F}\u{85}\u{9F}",
+            // format chars, invisible
+            "\u{AD}\u{200B}\u{200D}\u{200E}\u{202E}\u{2060}\u{FEFF}\u{E0001}",
+            // space, line and paragraph separators
+            " \u{A0}\u{2028}\u{2029}\u{3000}",
+        ];
+
+        let category = CATEGORIES[self.0.usize(0..CATEGORIES.len())];
+        let count = category.chars().count();
+        category.chars().nth(self.0.usize(0..count)).unwrap()
+    }
+
+    /// Generate a random ASCII control `char`, in `'\0'..='\u{1F}'` or `'\u{7F}'`
+    pub fn control_char(&mut self) -> char {
+        match self.0.u8(0..=0x20) {
+            0x20 => '\u{7F}',
+            c => c as char,
+        }
+    }
+
+    /// Generate a random whitespace `char`
+    ///
+    /// All the chars with the Unicode `White_Space` property are equally
+    /// likely, this includes the ASCII whitespace, but also the more exotic
+    /// no-break spaces, the line separator `'\u{2028}'` and the paragraph
+    /// separator `'\u{2029}'`.
+    pub fn whitespace_char(&mut self) -> char {
+        const WHITESPACE: [char; 25] = [
+            '\t', '\n', '\u{0B}', '\u{0C}', '\r', ' ', '\u{85}', '\u{A0}', '\u{1680}', '\u{2000}',
+            '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}', '\u{2005}', '\u{2006}', '\u{2007}',
+            '\u{2008}', '\u{2009}', '\u{200A}', '\u{2028}', '\u{2029}', '\u{202F}', '\u{205F}',
+            '\u{3000}',
+        ];
+        WHITESPACE[self.0.usize(0..WHITESPACE.len())]
+    }
+
+    /// Generate a random `bool`, `true` and `false` are equally likely.
+    pub fn bool(&mut self) -> bool {
+        self.0.bool()
+    }
+
+    /// Generate a random `bool`, which is `true` with probability `p_true`.
+    ///
+    /// The probability is clamped to `0.0..=1.0`, and a `NAN` probability is
+    /// treated as `0.0`. A probability of exactly `0.0` always gives `false`
+    /// and exactly `1.0` always gives `true`.
+    pub fn weird_bool(&mut self, p_true: f64) -> bool {
+        if p_true.is_nan() || p_true <= 0.0 {
+            false
+        } else if p_true >= 1.0 {
+            true
+        } else {
+            self.0.f64() < p_true
+        }
+    }
+
+    /// Generate a random line ending
+    ///
+    /// All the following are equally likely: `"\n"`, `"\r\n"`, `"\r"`,
+    /// `"\n\r"`, the line separator `"\u{2028}"`, the paragraph separator
+    /// `"\u{2029}"` and the empty string.
+    pub fn newline(&mut self) -> &'static str {
+        const NEWLINES: [&str; 7] = ["\n", "\r\n", "\r", "\n\r", "\u{2028}", "\u{2029}", ""];
+        NEWLINES[self.0.usize(0..NEWLINES.len())]
+    }
+
+    /// Fill a byte slice with random bytes, such that boundary bytes like
+    /// `0x00`, `0xFF`, `0x7F` and `0x80` are much more common than normal.
+    ///
+    /// About half of the bytes are picked from the boundary bytes, the rest
+    /// are uniformly random. This is much faster than calling [Wdg::u8] for
+    /// each byte, as the randomness is drawn in bulk.
+    ///
+    /// Returns the number of boundary bytes that were inserted. Note that a
+    /// uniformly random byte may also happen to be a boundary byte, these are
+    /// not counted.
+    pub fn fill_weird_bytes(&mut self, bytes: &mut [u8]) -> usize {
+        const SPECIAL: [u8; 8] = [0x00, 0x01, 0x7E, 0x7F, 0x80, 0x81, 0xFE, 0xFF];
+
+        self.0.fill(bytes);
+
+        let mut count = 0;
+        for chunk in bytes.chunks_mut(8) {
+            // one byte of entropy per output byte: the lowest bit decides
+            // whether to insert a boundary byte, the next three pick which one
+            let selectors = self.0.u64(..).to_le_bytes();
+            for (byte, selector) in chunk.iter_mut().zip(selectors) {
+                // branchless, as the branch would be mispredicted half the time
+                let is_special = (selector & 1) ^ 1;
+                let mask = is_special.wrapping_neg();
+                let special = SPECIAL[(selector >> 1) as usize & 0b111];
+                *byte = (special & mask) | (*byte & !mask);
+                count += is_special as usize;
+            }
+        }
+        count
+    }
+
+    uint!(self, [u8, u16, u32, u64, u128, usize]);
+
+    int!(self, [i8, i16, i32, i64, i128, isize]);
+
+    bits!(
+        self,
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+    );
+
+    range!(
+        self,
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+    );
+
+    powers!(
+        self,
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+    );
+
+    small!(
+        self,
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+    );
+}
+
+#[cfg(test)]
+mod test_unit {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn nan_f32() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(gen.nan_f32().is_nan());
+    }
+
+    #[test]
+    fn nan_f64() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(gen.nan_f64().is_nan());
+    }
+
+    #[test]
+    fn subnormal_f32() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(gen.subnormal_f32().is_subnormal());
+    }
+
+    #[test]
+    fn subnormal_f64() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(gen.subnormal_f64().is_subnormal());
+    }
+
+    #[test]
+    fn normal_f32() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(!gen.normal_f32().is_subnormal());
+    }
+
+    #[test]
+    fn normal_f64() {
+        let mut gen = Wdg::with_seed(0);
+        assert!(!gen.normal_f64().is_subnormal());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fork_n() {
+        let mut gen = Wdg::with_seed(0x0e_b5_63_f2_9a_17_c8_4d);
+        let mut children = gen.fork_n(8);
+        assert_eq!(children.len(), 8);
+
+        let mut draws: std::vec::Vec<u64> = children.iter_mut().map(|c| c.0.u64(..)).collect();
+        draws.sort_unstable();
+        draws.dedup();
+        assert_eq!(draws.len(), 8);
+
+        assert!(gen.fork_n(0).is_empty());
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let mut gen = Wdg::with_seed(0xbd_05_7a_e4_39_c2_68_f1);
+        for _ in 0..37 {
+            gen.f64();
+        }
+
+        let mut restored = Wdg::from_bytes(gen.to_bytes());
+        for _ in 0..100 {
+            assert!(float_utils::f64_exact_eq(gen.f64(), restored.f64()));
+            assert_eq!(gen.u128(), restored.u128());
+        }
+    }
+
+    #[test]
+    fn to_from_bytes_loses_weights() {
+        let mut gen = Wdg::builder()
+            .seed(0x7e_24_b9_c1_05_da_63_f8)
+            .exclude_nan()
+            .build();
+        let mut restored = Wdg::from_bytes(gen.to_bytes());
+        assert_eq!(restored.get_seed(), gen.get_seed());
+        assert_eq!(restored.float_weights(), &FloatWeights::default());
+        assert_ne!(restored.float_weights(), gen.float_weights());
+
+        let mut had_nan = false;
+        for _ in 0..1000 {
+            assert!(!gen.f64().is_nan());
+            had_nan |= restored.f64().is_nan();
+        }
+        assert!(had_nan);
+    }
+
+    #[test]
+    fn get_seed() {
+        let gen = Wdg::with_seed(0x4b_e0_97_1c_a6_3d_f5_82);
+        let mut a = gen.clone();
+        let mut b = gen.clone();
+        for _ in 0..100 {
+            let seed = a.get_seed();
+            assert_eq!(a.get_seed(), seed);
+            assert_eq!(a.get_seed(), b.get_seed());
+            assert_eq!(a.u64(), b.u64());
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let mut gen = Wdg::with_seed(0x0123_4567_89ab_cdef);
+        let mut expected = gen.clone();
+        assert_eq!(
+            std::format!("{:?}", gen),
+            "Wdg { seed: 0x0123456789abcdef }"
+        );
+        assert_eq!(gen.u64(), expected.u64());
+
+        let printed = std::format!("{:?}", gen);
+        let mut restored = Wdg::with_seed(gen.0.get_seed());
+        assert!(printed.contains(&std::format!("{:x}", gen.0.get_seed())));
+        assert_eq!(gen.u64(), restored.u64());
+    }
+
+    #[test]
+    fn seeded_by() {
+        let mut a = Wdg::seeded_by("weird_test_case");
+        let mut b = Wdg::seeded_by("weird_test_case");
+        for _ in 0..100 {
+            assert_eq!(a.u64(), b.u64());
+        }
+
+        // the seeds must not change across platforms or versions
+        assert_eq!(
+            Wdg::seeded_by("weird_test_case").get_seed(),
+            5573317591280890857
+        );
+        assert_eq!(
+            Wdg::seeded_by(&(42usize, -1i32)).get_seed(),
+            4963138116702272187
+        );
+
+        assert_ne!(
+            Wdg::seeded_by("weird_test_case").get_seed(),
+            Wdg::seeded_by("weird_test_cases").get_seed()
+        );
+        assert_ne!(
+            Wdg::seeded_by(&[1u8, 2]).get_seed(),
+            Wdg::seeded_by(&[2u8, 1]).get_seed()
+        );
+    }
+
+    #[test]
+    fn jump() {
+        let mut gen = Wdg::with_seed(0x2e_b8_51_f6_0d_c4_93_7a);
+        let mut expected = gen.clone();
+        gen.jump(10);
+        for _ in 0..10 {
+            expected.0.u64(..);
+        }
+        assert_eq!(gen.get_seed(), expected.get_seed());
+        assert_eq!(gen.u64(), expected.u64());
+
+        let seed = gen.get_seed();
+        gen.jump(0);
+        assert_eq!(gen.get_seed(), seed);
+        gen.jump(u64::MAX);
+        gen.jump(1);
+        assert_eq!(gen.get_seed(), seed);
+    }
+
+    #[test]
+    fn with_entropy() {
+        let mut calls = 0;
+        let mut gen = Wdg::with_entropy(|| {
+            calls += 1;
+            0x95_2c_d8_43_f1_0a_b7_6e
+        });
+        assert_eq!(calls, 1);
+
+        let mut expected = Wdg::with_seed(0x95_2c_d8_43_f1_0a_b7_6e);
+        for _ in 0..100 {
+            assert_eq!(gen.u64(), expected.u64());
+        }
+    }
+
+    #[test]
+    fn builder() {
+        let mut gen = Wdg::builder().seed(0x5f_e2_8a_31_c6_0d_b9_74).build();
+        let mut expected = Wdg::with_seed(0x5f_e2_8a_31_c6_0d_b9_74);
+        for _ in 0..100 {
+            assert!(float_utils::f32_exact_eq(gen.f32(), expected.f32()));
+        }
+
+        let mut gen = Wdg::builder()
+            .seed(0x5f_e2_8a_31_c6_0d_b9_74)
+            .exclude_nan()
+            .exclude_subnormal()
+            .build();
+        assert_eq!(*gen.float_weights(), FloatWeights::new(1, 0, 0, 1));
+        assert!(std::format!("{:?}", gen).contains("weights"));
+        let mut fork = gen.fork();
+        let mut stream = gen.stream(0);
+        for _ in 0..1000 {
+            for x in [gen.f64(), fork.f64(), stream.f64()] {
+                assert!(!x.is_nan() && !x.is_subnormal(), "{x:?}");
+            }
+        }
+
+        let counts = Wdg::builder()
+            .seed(0x5f_e2_8a_31_c6_0d_b9_74)
+            .exclude_normal()
+            .build()
+            .sample_f32_categories(3000);
+        assert_eq!(counts.normal, 0);
+        for count in [counts.subnormal, counts.nan, counts.special] {
+            assert!((900..1100).contains(&count), "{:?}", counts);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "all float categories are excluded")]
+    fn builder_excludes_all() {
+        let _ = Wdg::builder()
+            .seed(0)
+            .exclude_normal()
+            .exclude_subnormal()
+            .exclude_nan()
+            .exclude_special()
+            .build();
+    }
+
+    #[test]
+    fn stream() {
+        let gen = Wdg::with_seed(0x71_d4_0c_e8_3b_96_a2_5f);
+        let seed = gen.0.get_seed();
+
+        let mut a = gen.stream(1);
+        let mut b = gen.stream(2);
+        assert_ne!(a.0.u64(..), b.0.u64(..));
+
+        let mut a = gen.stream(1);
+        let mut a_again = gen.stream(1);
+        for _ in 0..100 {
+            assert_eq!(a.u64(), a_again.u64());
+        }
+
+        // the parent is untouched
+        assert_eq!(gen.0.get_seed(), seed);
+        assert_eq!(
+            Wdg::with_seed(seed).stream(1).0.get_seed(),
+            gen.stream(1).0.get_seed()
+        );
+    }
+
+    #[test]
+    fn exact_eq() {
+        use float_utils::{f32_exact_eq, f64_exact_eq};
+
+        assert!(!f32_exact_eq(0.0, -0.0));
+        assert!(f32_exact_eq(f32::NAN, f32::NAN));
+        assert!(!f32_exact_eq(
+            f32::NAN,
+            f32::from_bits(f32::NAN.to_bits() | 1)
+        ));
+        assert!(!f64_exact_eq(0.0, -0.0));
+        assert!(f64_exact_eq(f64::NAN, f64::NAN));
+        assert!(!f64_exact_eq(
+            f64::NAN,
+            f64::from_bits(f64::NAN.to_bits() | 1)
+        ));
+    }
+
+    #[test]
+    fn quiet_signaling_nan() {
+        use float_utils::*;
+
+        let mut gen = Wdg::with_seed(0);
+        let (quiet, signaling) = (gen.quiet_nan_f32(), gen.signaling_nan_f32());
+        assert!(f32_is_quiet_nan(quiet) && !f32_is_signaling_nan(quiet));
+        assert!(f32_is_signaling_nan(signaling) && !f32_is_quiet_nan(signaling));
+        assert!(!f32_is_quiet_nan(f32::INFINITY) && !f32_is_signaling_nan(f32::INFINITY));
+
+        let (quiet, signaling) = (gen.quiet_nan_f64(), gen.signaling_nan_f64());
+        assert!(f64_is_quiet_nan(quiet) && !f64_is_signaling_nan(quiet));
+        assert!(f64_is_signaling_nan(signaling) && !f64_is_quiet_nan(signaling));
+        assert!(!f64_is_quiet_nan(f64::INFINITY) && !f64_is_signaling_nan(f64::INFINITY));
+    }
+}
+
+#[cfg(test)]
+mod test_fuzz {
+    // fuzzing tests, they may take a while to run. Shouldn't last more than
+    // a minute per test (or I'll get impatient/cargo will complain)
+
+    extern crate std;
+
+    use crate::float_utils::{
+        f32_exact_eq, f32_is_signaling_nan, f64_exact_eq, f64_is_signaling_nan,
+    };
+
+    use super::*;
+
+    // TODO: all seeds here should be picked at random from RANDOM.org
+
+    #[test]
+    #[ignore]
+    fn nan_f32_is_nan() {
+        let mut gen = Wdg::with_seed(0x0b_65_58_2b_4e_d8_20_fe);
+        for i in 0..(1 << 30) {
+            let num = gen.nan_f32();
+            assert!(num.is_nan(), "{}: {:032b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn nan_f64_is_nan() {
+        let mut gen = Wdg::with_seed(0x36_44_3e_f8_40_af_6e_49);
+        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
+        //       way too many to guess the bad ones at random. Maybe do something
+        //       meta where you use this crate to fuzz itself?
+        for i in 0..1 << 30 {
+            let num = gen.nan_f64();
+            assert!(num.is_nan(), "{}: {:064b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    fn nan_f32_range() {
+        let mut gen = Wdg::with_seed(0x29_21_f1_bd_8b_a9_c6_b6);
+        let mut coverage: u32 = 0b0;
+        for _ in 0..10000 {
+            let num = gen.nan_f32();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+    }
+
+    #[test]
+    fn nan_f64_range() {
+        let mut gen = Wdg::with_seed(0x6f_35_67_53_e6_37_13_c3);
+        let mut coverage: u64 = 0b0;
+        for _ in 0..10000 {
+            let num = gen.nan_f64();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    }
+
+    #[test]
+    fn nan_f64_covering_range() {
+        let mut gen = Wdg::with_seed(0x93_1e_c7_4a_d0_6b_f8_25);
+        let mut seen = std::vec![false; Wdg::NAN_F64_COVERING_CHUNKS as usize];
+        let mut coverage: u64 = 0b0;
+        for chunk in 0..Wdg::NAN_F64_COVERING_CHUNKS {
+            let num = gen.nan_f64_covering(chunk);
+            assert!(num.is_nan(), "{}: {:064b}", chunk, num.to_bits());
+            coverage |= num.to_bits();
+
+            let top = (num.to_bits() >> 63) << 11 | (num.to_bits() >> 41) & 0b111_1111_1111;
+            assert!(!seen[top as usize]);
+            seen[top as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+
+        let num = gen.nan_f64_covering(Wdg::NAN_F64_COVERING_CHUNKS + 5);
+        assert_eq!(num.to_bits() >> 41, gen.nan_f64_covering(5).to_bits() >> 41);
+    }
+
+    #[test]
+    fn quiet_nan_f32_range() {
+        let mut gen = Wdg::with_seed(0x5e_90_c2_7d_18_a3_4b_f6);
+        let mut coverage: u32 = 0b0;
+        for _ in 0..10000 {
+            let num = gen.quiet_nan_f32();
+            assert!(num.is_nan() && !f32_is_signaling_nan(num));
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+    }
+
+    #[test]
+    fn quiet_nan_f64_range() {
+        let mut gen = Wdg::with_seed(0xb7_21_6d_e8_03_5c_9a_4f);
+        let mut coverage: u64 = 0b0;
+        for _ in 0..10000 {
+            let num = gen.quiet_nan_f64();
+            assert!(num.is_nan() && !f64_is_signaling_nan(num));
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    }
+
+    #[test]
+    fn signaling_nan_f32_range() {
+        let mut gen = Wdg::with_seed(0x0f_84_b3_59_e2_c6_17_7a);
+        let mut coverage: u32 = 1 << 22;
+        for _ in 0..10000 {
+            let num = gen.signaling_nan_f32();
+            assert!(f32_is_signaling_nan(num), "{:032b}", num.to_bits());
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+    }
+
+    #[test]
+    fn signaling_nan_f64_range() {
+        let mut gen = Wdg::with_seed(0x92_4e_1d_a7_6b_f0_38_c5);
+        let mut coverage: u64 = 1 << 51;
+        for _ in 0..10000 {
+            let num = gen.signaling_nan_f64();
+            assert!(f64_is_signaling_nan(num), "{:064b}", num.to_bits());
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    }
+
+    #[test]
+    #[ignore]
+    fn subnoraml_f32_is_subnormal() {
+        let mut gen = Wdg::with_seed(0x52_58_4a_d1_55_e1_72_10);
+        for i in 0..(1 << 30) {
+            let num = gen.subnormal_f32();
+            assert!(num.is_subnormal(), "{}: {:032b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn subnormal_f64_is_subnormal() {
+        let mut gen = Wdg::with_seed(0x2d_46_cc_c0_45_c5_ec_03);
+        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
+        //       way too many to guess the bad ones at random. Maybe do something
+        //       meta where you use this crate to fuzz itself?
+        for i in 0..1 << 30 {
+            let num = gen.subnormal_f64();
+            assert!(num.is_subnormal(), "{}: {:064b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    fn subnormal_f32_range() {
+        let mut gen = Wdg::with_seed(0x98_fb_6b_ef_ac_5d_81_f3);
+        let mut coverage: u32 = 0b1111_1111 << 23;
+        for _ in 0..10000 {
+            let num = gen.subnormal_f32();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+    }
+
+    #[test]
+    fn subnormal_f64_range() {
+        let mut gen = Wdg::with_seed(0x7a_07_58_14_f4_b8_2f_49);
+        let mut coverage: u64 = 0b111_1111_1111 << 52;
+        for _ in 0..10000 {
+            let num = gen.subnormal_f64();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    }
+
+    #[test]
+    #[ignore]
+    fn noraml_f32_is_not_subnormal() {
+        let mut gen = Wdg::with_seed(0x2c_fe_59_bb_7a_56_28_20);
+        for i in 0..(1 << 30) {
+            let num = gen.normal_f32();
+            assert!(!num.is_subnormal(), "{}: {:032b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn normal_f64_is_not_subnormal() {
+        let mut gen = Wdg::with_seed(0xa9_26_d1_d9_7b_d7_94_15);
+        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
+        //       way too many to guess the bad ones at random. Maybe do something
+        //       meta where you use this crate to fuzz itself?
+        for i in 0..1 << 30 {
+            let num = gen.normal_f64();
+            assert!(!num.is_subnormal(), "{}: {:064b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    fn normal_f32_exponent_range() {
+        let mut gen = Wdg::with_seed(0xd3_7f_08_b2_61_ce_95_4a);
+        for _ in 0..10000 {
+            let num = gen.normal_f32();
+            let exponent = (num.to_bits() >> 23) & 0b1111_1111;
+            assert!((1..=0xFE).contains(&exponent), "{:032b}", num.to_bits());
+        }
+    }
+
+    #[test]
+    fn normal_f64_exponent_range() {
+        let mut gen = Wdg::with_seed(0x41_c9_2a_f5_7d_06_e3_b8);
+        for _ in 0..10000 {
+            let num = gen.normal_f64();
+            let exponent = (num.to_bits() >> 52) & 0b111_1111_1111;
+            assert!((1..=0x7FE).contains(&exponent), "{:064b}", num.to_bits());
+        }
+    }
+
+    #[test]
+    fn normal_f32_range() {
+        let mut gen = Wdg::with_seed(0x15_63_e3_11_09_cb_11_b5);
+        let mut coverage: u32 = 0;
+        for _ in 0..10000 {
+            let num = gen.normal_f32();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+    }
+
+    #[test]
+    fn normal_f64_range() {
+        let mut gen = Wdg::with_seed(0x56_e5_19_b1_47_f2_5e_0d);
+        let mut coverage: u64 = 0;
+        for _ in 0..10000 {
+            let num = gen.normal_f64();
+            coverage |= num.to_bits();
+        }
+
+        // every bit should be generated at least once, given enough attempts
+        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    }
+
+    #[test]
+    fn special_f32() {
+        let mut gen = Wdg::with_seed(0x69_1b_e9_82_15_ed_a0_7d);
+        for _ in 0..10000 {
+            gen.special_f32();
+        }
+    }
+
+    #[test]
+    fn special_f64() {
+        let mut gen = Wdg::with_seed(0xf5_31_9e_51_c4_1f_9e_35);
+        for _ in 0..10000 {
+            gen.special_f64();
+        }
+    }
+
+    #[test]
+    fn special_char_range() {
+        let mut gen = Wdg::with_seed(0x3c_d1_7e_02_9b_44_f5_a8);
+        let mut had_nul = false;
+        let mut had_max = false;
+        let mut had_before_surrogates = false;
+        let mut had_after_surrogates = false;
+        let mut had_replacement = false;
+        let mut had_bom = false;
+        for _ in 0..10000 {
+            let c = gen.special_char();
+            had_nul |= c == '\0';
+            had_max |= c == '\u{10FFFF}';
+            had_before_surrogates |= c == '\u{D7FF}';
+            had_after_surrogates |= c == '\u{E000}';
+            had_replacement |= c == '\u{FFFD}';
+            had_bom |= c == '\u{FEFF}';
+        }
+        assert!(
+            had_nul
+                && had_max
+                && had_before_surrogates
+                && had_after_surrogates
+                && had_replacement
+                && had_bom
+        );
+    }
+
+    #[test]
+    fn control_char_range() {
+        let mut gen = Wdg::with_seed(0x1b_7e_c5_08_f2_a4_63_d9);
+        let mut seen = [false; 0x80];
+        for _ in 0..10000 {
+            let c = gen.control_char();
+            assert!(c.is_ascii_control(), "{:?}", c);
+            seen[c as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&s| s).count(), 0x21);
+    }
+
+    #[test]
+    fn whitespace_char_range() {
+        let mut gen = Wdg::with_seed(0x8d_42_f0_b7_1e_c9_a5_36);
+        let mut had_line_separator = false;
+        let mut had_paragraph_separator = false;
+        let mut had_nbsp = false;
+        for _ in 0..10000 {
+            let c = gen.whitespace_char();
+            assert!(c.is_whitespace(), "{:?}", c);
+            had_line_separator |= c == '\u{2028}';
+            had_paragraph_separator |= c == '\u{2029}';
+            had_nbsp |= c == '\u{A0}';
+        }
+        assert!(had_line_separator && had_paragraph_separator && had_nbsp);
+    }
+
+    #[test]
+    fn newline() {
+        let mut gen = Wdg::with_seed(0xa6_1f_d8_53_0c_e7_b2_49);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..1000 {
+            seen.insert(gen.newline());
+        }
+        assert_eq!(seen.len(), 7);
+    }
+
+    #[test]
+    fn weird_char_category() {
+        let mut gen = Wdg::with_seed(0x8e_03_d5_7a_c1_f9_46_b2);
+        let mut seen = [false; 6];
+        for _ in 0..100 {
+            let c = gen.weird_char_category();
+            seen[0] |= c.is_alphabetic();
+            seen[1] |= c.is_numeric();
+            seen[2] |= c.is_control();
+            seen[3] |= c.is_whitespace() && !c.is_control();
+            seen[4] |= c.is_ascii_punctuation();
+            seen[5] |=
+                !c.is_alphanumeric() && !c.is_control() && !c.is_whitespace() && !c.is_ascii();
+        }
+        assert_eq!(seen, [true; 6]);
+    }
+
+    #[test]
+    fn char_range() {
+        let mut gen = Wdg::with_seed(0xe4_0a_5f_91_26_c8_7b_13);
+        let mut had_above_surrogates = false;
+        let mut had_below_surrogates = false;
+        for _ in 0..10000 {
+            let scalar = gen.char() as u32;
+            assert!(!(0xD800..=0xDFFF).contains(&scalar), "{:x}", scalar);
+            had_below_surrogates |= scalar < 0xD7FF;
+            had_above_surrogates |= scalar > 0xE000 && scalar != 0xFFFD && scalar != 0xFEFF;
+        }
+        assert!(had_below_surrogates && had_above_surrogates);
+    }
+
+    #[test]
+    fn fill_weird_bytes() {
+        let mut gen = Wdg::with_seed(0xb1_5c_8e_27_f4_0d_96_3a);
+        let mut bytes = [0u8; 10000];
+        let count = gen.fill_weird_bytes(&mut bytes);
+        assert!((4500..5500).contains(&count), "{}", count);
+
+        for special in [0x00, 0x01, 0x7E, 0x7F, 0x80, 0x81, 0xFE, 0xFF] {
+            assert!(bytes.contains(&special), "{:02x}", special);
+        }
+
+        let mut coverage = [false; 256];
+        for byte in bytes {
+            coverage[byte as usize] = true;
+        }
+        assert!(coverage.iter().all(|&c| c));
+
+        assert_eq!(gen.fill_weird_bytes(&mut []), 0);
+    }
+
+    macro_rules! int_uint {
+        ($($t:ty),+ $(,)?) => {
+            $(
+                int_uint_inner!($t);
+            )+
+        };
+    }
+
+    macro_rules! int_uint_inner {
+        ($t:ty) => {
+            paste! {
+                #[test]
+                pub fn [<special_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x29_2d_3a_df_ed_dd_c0_82);
+                    for _ in 0..10000 {
+                        gen.[<special_ $t>]();
+                    }
+                }
+
+                #[test]
+                pub fn [<special_nonzero_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x4f_0b_93_d6_2e_75_a1_c8);
+                    for _ in 0..10000 {
+                        assert_ne!(gen.[<special_nonzero_ $t>]().get(), 0);
+                    }
+                }
+
+                #[test]
+                pub fn [<nonzero_ $t>]() {
+                    let mut gen = Wdg::with_seed(0xa8_e6_15_7c_d0_39_4b_f2);
+                    for _ in 0..10000 {
+                        assert_ne!(gen.[<nonzero_ $t>]().get(), 0);
+                    }
+                }
+
+                #[test]
+                pub fn [<special_bits_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x3d_e7_82_0a_c5_6f_19_b4);
+                    let mut had_alternating_a = false;
+                    let mut had_alternating_5 = false;
+                    let mut had_sign_bit = false;
+                    for _ in 0..10000 {
+                        let x = gen.[<special_bits_ $t>]();
+                        had_alternating_a |= x == 0xAAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_u128 as $t;
+                        had_alternating_5 |= x == 0x5555_5555_5555_5555_5555_5555_5555_5555_u128 as $t;
+                        had_sign_bit |= x.leading_zeros() == 0 && x.count_ones() == 1;
+                    }
+                    assert!(had_alternating_a && had_alternating_5 && had_sign_bit);
+                }
+
+                #[test]
+                pub fn [<near_boundary_ $t>]() {
+                    let mut gen = Wdg::with_seed(0xe2_19_7b_c4_5d_a0_36_8f);
+                    let mut had_max_minus_one = false;
+                    let mut had_min_plus_one = false;
+                    for _ in 0..10000 {
+                        let x = gen.[<near_boundary_ $t>]();
+                        assert!(x <= $t::MIN + 2 || x >= $t::MAX - 2 || (-1_i8 as $t..=1).contains(&x));
+                        had_max_minus_one |= x == $t::MAX - 1;
+                        had_min_plus_one |= x == $t::MIN + 1;
+                    }
+                    assert!(had_max_minus_one && had_min_plus_one);
+                }
+
+                #[test]
+                pub fn [<near_value_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x4a_b8_03_f6_91_2d_e5_7c);
+                    let mut had_max = false;
+                    let mut had_min = false;
+                    for _ in 0..10000 {
+                        let x = gen.[<near_value_ $t>]($t::MAX - 1);
+                        assert!(x >= $t::MAX - 4);
+                        had_max |= x == $t::MAX;
+
+                        let x = gen.[<near_value_ $t>]($t::MIN + 1);
+                        assert!(x <= $t::MIN + 4);
+                        had_min |= x == $t::MIN;
+
+                        let x = gen.[<near_value_ $t>](42);
+                        assert!((39..=45).contains(&x));
+                    }
+                    assert!(had_max && had_min);
+                }
+
+                #[test]
+                pub fn [<special_ $t _values>]() {
+                    let values: std::vec::Vec<$t> = Wdg::[<special_ $t _values>]().collect();
+                    #[cfg(feature = "alloc")]
+                    assert_eq!(Wdg::[<all_special_ $t>](), values);
+                    let mut seen = std::vec![false; values.len()];
+                    let mut gen = Wdg::with_seed(0xf1_6b_a8_25_dc_07_93_4e);
+                    for _ in 0..10000 {
+                        let x = gen.[<special_ $t>]();
+                        let i = values.iter().position(|v| *v == x).unwrap();
+                        seen[i] = true;
+                    }
+                    assert!(seen.iter().all(|&s| s));
+
+                    let mut unique = values.clone();
+                    unique.sort_unstable();
+                    unique.dedup();
+                    assert_eq!(unique.len(), values.len());
+                }
+
+                #[test]
+                pub fn [<weird_range_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x97_0c_e4_5b_a3_f8_21_6d);
+                    let mut had_first = false;
+                    let mut had_last = false;
+                    let mut had_mid = false;
+                    for _ in 0..1000 {
+                        let x = gen.[<weird_range_ $t>](10..21);
+                        assert!((10..21).contains(&x));
+                        had_first |= x == 10;
+                        had_last |= x == 20;
+                        had_mid |= x == 15;
+
+                        assert_eq!(gen.[<weird_range_ $t>](7..8), 7);
+
+                        let x = gen.[<weird_range_ $t>]($t::MIN..$t::MAX);
+                        assert!(x != $t::MAX);
+                    }
+                    assert!(had_first && had_last && had_mid);
+                }
+
+                #[test]
+                #[should_panic(expected = "empty range")]
+                pub fn [<weird_range_ $t _empty>]() {
+                    let mut gen = Wdg::with_seed(0x1c_a5_7e_30_d9_62_fb_84);
+                    gen.[<weird_range_ $t>](5..5);
+                }
+
+                #[test]
+                pub fn [<power_of_two_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x2b_e8_51_c6_0f_9d_74_a3);
+                    let mut seen = [false; 128];
+                    for _ in 0..10000 {
+                        let x = gen.[<power_of_two_ $t>]();
+                        assert!(x > 0 && x.count_ones() == 1);
+                        seen[x.trailing_zeros() as usize] = true;
+                    }
+                    let largest = $t::MAX.ilog2() as usize;
+                    assert!(seen[..=largest].iter().all(|&s| s));
+                    assert!(seen[largest + 1..].iter().all(|&s| !s));
+                }
+
+                #[test]
+                pub fn [<power_of_ten_ $t>]() {
+                    use std::string::ToString;
+
+                    let mut gen = Wdg::with_seed(0xd4_0a_6e_93_b7_25_f1_c8);
+                    let mut had_one = false;
+                    let mut had_largest = false;
+                    for _ in 0..10000 {
+                        let x = gen.[<power_of_ten_ $t>]();
+                        assert!(x > 0 && x.to_string().trim_start_matches('1').bytes().all(|b| b == b'0'));
+                        had_one |= x == 1;
+                        if x.checked_mul(10).is_none() {
+                            had_largest = true;
+                            assert_eq!(x.to_string().len(), $t::MAX.to_string().len());
+                        }
+                    }
+                    assert!(had_one && had_largest);
+                }
+
+                #[test]
+                pub fn [<overflowing_pairs_ $t>]() {
+                    let mut gen = Wdg::with_seed(0xc8_47_1e_b3_95_0a_6d_f2);
+                    let mut had_barely_add = false;
+                    let mut had_barely_sub = false;
+                    for _ in 0..10000 {
+                        let (a, b) = gen.[<overflowing_add_pair_ $t>]();
+                        assert!(a.checked_add(b).is_none(), "{a} + {b}");
+                        had_barely_add |= a.wrapping_add(b) == $t::MIN;
+
+                        let (a, b) = gen.[<overflowing_sub_pair_ $t>]();
+                        assert!(a.checked_sub(b).is_none(), "{a} - {b}");
+                        had_barely_sub |= a.wrapping_sub(b) == $t::MAX;
+
+                        let (a, b) = gen.[<overflowing_mul_pair_ $t>]();
+                        assert!(a.checked_mul(b).is_none(), "{a} * {b}");
+                    }
+                    assert!(had_barely_add && had_barely_sub);
+                }
+
+                #[test]
+                pub fn [<$t _array>]() {
+                    let mut gen = Wdg::with_seed(0xa2_6d_f0_19_c7_3b_e4_58);
+                    let mut expected = gen.clone();
+                    let array: [$t; 8] = gen.[<$t _array>]();
+                    for x in array {
+                        assert_eq!(x, expected.$t());
+                    }
+                }
+
+                #[test]
+                pub fn [<weird_ratio_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x6e_93_0b_d5_a1_4c_f7_28);
+                    let mut had_zero = false;
+                    let mut had_one = false;
+                    let mut had_equal = false;
+                    for _ in 0..1000 {
+                        let (num, den) = gen.[<weird_ratio_ $t>]();
+                        had_zero |= den == 0;
+                        had_one |= den == 1;
+                        had_equal |= num == den && num != 0;
+                    }
+                    assert!(had_zero && had_one && had_equal);
+                }
+
+                #[test]
+                pub fn [<small_biased_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x38_f2_a5_1c_d9_64_0e_b7);
+                    let mut small = 0;
+                    let mut had_wide = false;
+                    for _ in 0..10000 {
+                        let x = gen.[<small_biased_ $t>]();
+                        small += (x as i128).unsigned_abs().le(&255) as u32;
+                        had_wide |= (x as i128).unsigned_abs() > 255 && x != $t::MAX && x != $t::MIN;
+                    }
+                    assert!(small > 5000, "{small}");
+                    if $t::BITS > 8 {
+                        assert!(had_wide);
+                    }
+                }
+
+                #[test]
+                pub fn [<iter_ $t>]() {
+                    let mut gen = Wdg::with_seed(0x62_f9_d4_0b_a7_3c_e1_58);
+                    let mut expected = gen.clone();
+                    for x in gen.[<iter_ $t>]().take(1000) {
+                        assert_eq!(x, expected.$t());
+                    }
+                }
+
+                #[test]
+                pub fn $t(){
+                    let mut gen = Wdg::with_seed(0x8e_bd_46_37_50_b4_9b_1a);
+                    for _ in 0..10000 {
+                        gen.$t();
+                    }
+                }
+            }
+        };
+    }
+
+    int_uint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    #[test]
+    fn special_f32_range() {
+        let mut gen = Wdg::with_seed(0x90_ae_72_03_34_a0_d7_4b);
+        let mut had_infinite = false;
+        let mut had_neg_infinite = false;
+        let mut had_zero = false;
+        let mut had_neg_zero = false;
+        let mut had_one = false;
+        let mut had_neg_one = false;
+        let mut had_min_positive = false;
+        let mut had_max_negative = false;
+        let mut had_epsilon = false;
+        let mut had_neg_epsilon = false;
+        for _ in 0..10000 {
+            let num = gen.special_f32();
+            had_infinite |= f32_exact_eq(num, f32::INFINITY);
+            had_neg_infinite |= f32_exact_eq(num, f32::NEG_INFINITY);
+            had_zero |= f32_exact_eq(num, 0.0);
+            had_neg_zero |= f32_exact_eq(num, -0.0);
+            had_one |= f32_exact_eq(num, 1.0);
+            had_neg_one |= f32_exact_eq(num, -1.0);
+            had_min_positive |= f32_exact_eq(num, f32::MIN_POSITIVE);
+            had_max_negative |= f32_exact_eq(num, -f32::MIN_POSITIVE);
+            had_epsilon |= f32_exact_eq(num, f32::EPSILON);
+            had_neg_epsilon |= f32_exact_eq(num, -f32::EPSILON);
+        }
+        assert!(
+            had_infinite
+                && had_neg_infinite
+                && had_zero
+                && had_neg_zero
+                && had_one
+                && had_neg_one
+                && had_min_positive
+                && had_max_negative
+                && had_epsilon
+                && had_neg_epsilon
+        );
+    }
+
+    #[test]
+    fn special_f64_range() {
+        let mut gen = Wdg::with_seed(0x10_6c_a1_34_a5_6d_03_97);
+        let mut had_infinite = false;
+        let mut had_neg_infinite = false;
+        let mut had_zero = false;
+        let mut had_neg_zero = false;
+        let mut had_one = false;
+        let mut had_neg_one = false;
+        let mut had_min_positive = false;
+        let mut had_max_negative = false;
+        let mut had_epsilon = false;
+        let mut had_neg_epsilon = false;
+        for _ in 0..10000 {
+            let num = gen.special_f64();
+            had_infinite |= f64_exact_eq(num, f64::INFINITY);
+            had_neg_infinite |= f64_exact_eq(num, f64::NEG_INFINITY);
+            had_zero |= f64_exact_eq(num, 0.0);
+            had_neg_zero |= f64_exact_eq(num, -0.0);
+            had_one |= f64_exact_eq(num, 1.0);
+            had_neg_one |= f64_exact_eq(num, -1.0);
+            had_min_positive |= f64_exact_eq(num, f64::MIN_POSITIVE);
+            had_max_negative |= f64_exact_eq(num, -f64::MIN_POSITIVE);
+            had_epsilon |= f64_exact_eq(num, f64::EPSILON);
+            had_neg_epsilon |= f64_exact_eq(num, -f64::EPSILON);
+        }
+        assert!(
+            had_infinite
+                && had_neg_infinite
+                && had_zero
+                && had_neg_zero
+                && had_one
+                && had_neg_one
+                && had_min_positive
+                && had_max_negative
+                && had_epsilon
+                && had_neg_epsilon
+        );
+    }
+
+    #[test]
+    fn f32_range() {
+        let mut gen = Wdg::with_seed(0x7c_65_54_c7_d6_a9_d4_b7);
+
+        // these should all be true by the end, given enough attempts
+        let mut had_normal = false;
+        let mut had_subnormal = false;
+        let mut had_nan = false;
+        let mut had_special = false;
+        for _ in 0..10000 {
+            let num = gen.f32();
+            had_normal |= num.is_normal();
+            had_subnormal |= num.is_subnormal();
+            had_nan |= num.is_nan();
+            had_special |= num.is_infinite()
+                | f32_exact_eq(num, 0.0)
+                | f32_exact_eq(num, -0.0)
+                | f32_exact_eq(num, 1.0)
+                | f32_exact_eq(num, -1.0)
+                | f32_exact_eq(num, f32::MIN)
+                | f32_exact_eq(num, f32::MAX)
+                | f32_exact_eq(num, f32::MIN_POSITIVE)
+                | f32_exact_eq(num, -f32::MIN_POSITIVE)
+                | f32_exact_eq(num, f32::EPSILON)
+                | f32_exact_eq(num, -f32::EPSILON);
+        }
+        assert!(had_normal && had_subnormal && had_nan && had_special);
+    }
+
+    #[test]
+    fn f64_range() {
+        let mut gen = Wdg::with_seed(0x9a_a4_ee_0f_08_ba_d9_de);
+
+        // these should all be true by the end, given enough attempts
+        let mut had_normal = false;
+        let mut had_subnormal = false;
+        let mut had_nan = false;
+        let mut had_special = false;
+        for _ in 0..10000 {
+            let num = gen.f64();
+            had_normal |= num.is_normal();
+            had_subnormal |= num.is_subnormal();
+            had_nan |= num.is_nan();
+            had_special |= num.is_infinite()
+                | f64_exact_eq(num, 0.0)
+                | f64_exact_eq(num, -0.0)
+                | f64_exact_eq(num, 1.0)
+                | f64_exact_eq(num, -1.0)
+                | f64_exact_eq(num, f64::MIN)
+                | f64_exact_eq(num, f64::MAX)
+                | f64_exact_eq(num, f64::MIN_POSITIVE)
+                | f64_exact_eq(num, -f64::MIN_POSITIVE)
+                | f64_exact_eq(num, f64::EPSILON)
+                | f64_exact_eq(num, -f64::EPSILON);
+        }
+        assert!(had_normal && had_subnormal && had_nan && had_special);
+    }
+
+    #[test]
+    #[ignore]
+    fn finite_f32_is_finite() {
+        let mut gen = Wdg::with_seed(0x8b_13_f4_6e_a0_5d_c7_29);
+        for i in 0..(1 << 30) {
+            let num = gen.finite_f32();
+            assert!(num.is_finite(), "{}: {:032b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn finite_f64_is_finite() {
+        let mut gen = Wdg::with_seed(0x27_e5_9c_03_b8_4f_61_da);
+        for i in 0..(1 << 30) {
+            let num = gen.finite_f64();
+            assert!(num.is_finite(), "{}: {:064b}", i, num.to_bits());
+        }
+    }
+
+    #[test]
+    fn finite_f32_range() {
+        let mut gen = Wdg::with_seed(0xc6_48_0d_7a_e3_91_2f_b5);
+
+        // these should all be true by the end, given enough attempts
+        let mut had_normal = false;
+        let mut had_subnormal = false;
+        let mut had_neg_zero = false;
+        let mut had_max = false;
+        for _ in 0..10000 {
+            let num = gen.finite_f32();
+            assert!(num.is_finite(), "{:032b}", num.to_bits());
+            had_normal |= num.is_normal();
+            had_subnormal |= num.is_subnormal();
+            had_neg_zero |= f32_exact_eq(num, -0.0);
+            had_max |= f32_exact_eq(num, f32::MAX);
+        }
+        assert!(had_normal && had_subnormal && had_neg_zero && had_max);
+    }
+
+    #[test]
+    fn finite_f64_range() {
+        let mut gen = Wdg::with_seed(0x1f_a2_6b_d8_45_3e_90_c7);
+
+        // these should all be true by the end, given enough attempts
+        let mut had_normal = false;
+        let mut had_subnormal = false;
+        let mut had_neg_zero = false;
+        let mut had_max = false;
+        for _ in 0..10000 {
+            let num = gen.finite_f64();
+            assert!(num.is_finite(), "{:064b}", num.to_bits());
+            had_normal |= num.is_normal();
+            had_subnormal |= num.is_subnormal();
+            had_neg_zero |= f64_exact_eq(num, -0.0);
+            had_max |= f64_exact_eq(num, f64::MAX);
+        }
+        assert!(had_normal && had_subnormal && had_neg_zero && had_max);
+    }
+
+    #[test]
+    fn f32_weighted_excludes_zero_weights() {
+        let mut gen = Wdg::with_seed(0x6a_d0_3b_95_e7_12_c4_8f);
+        let weights = FloatWeights::new(0, 0, 1, 0);
+        for _ in 0..10000 {
+            assert!(gen.f32_weighted(&weights).is_nan());
+        }
+
+        let weights = FloatWeights::new(1, 1, 0, 1);
+        for _ in 0..10000 {
+            assert!(!gen.f32_weighted(&weights).is_nan());
+        }
+    }
+
+    #[test]
+    fn f64_weighted_excludes_zero_weights() {
+        let mut gen = Wdg::with_seed(0x93_5f_e1_28_7c_b4_0a_d6);
+        let weights = FloatWeights::new(0, 1, 0, 0);
+        for _ in 0..10000 {
+            assert!(gen.f64_weighted(&weights).is_subnormal());
+        }
+
+        let weights = FloatWeights::new(1, 0, 1, 1);
+        for _ in 0..10000 {
+            assert!(!gen.f64_weighted(&weights).is_subnormal());
+        }
+    }
+
+    #[test]
+    fn f32_weighted_proportions() {
+        let mut gen = Wdg::with_seed(0x0e_7b_c6_41_d9_38_a5_f2);
+        let weights = FloatWeights::new(1, 1, 7, 1);
+        let mut nans = 0;
+        for _ in 0..10000 {
+            nans += gen.f32_weighted(&weights).is_nan() as u32;
+        }
+        assert!((6500..7500).contains(&nans), "{}", nans);
+    }
+
+    #[test]
+    #[should_panic]
+    fn f32_weighted_all_zero() {
+        let mut gen = Wdg::with_seed(0);
+        gen.f32_weighted(&FloatWeights::new(0, 0, 0, 0));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn rand_core_seedable() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut a = Wdg::from_seed(0x5a_2c_e9_71_04_bd_86_f3_u64.to_le_bytes());
+        let mut b = Wdg::with_seed(0x5a_2c_e9_71_04_bd_86_f3);
+        let mut c = Wdg::seed_from_u64(0x5a_2c_e9_71_04_bd_86_f3);
+        for _ in 0..100 {
+            let x = a.next_u64();
+            assert_eq!(x, b.0.u64(..));
+            assert_eq!(x, c.next_u64());
+        }
+
+        let mut buf_a = [0u8; 37];
+        let mut buf_b = [0u8; 37];
+        a.fill_bytes(&mut buf_a);
+        b.try_fill_bytes(&mut buf_b).unwrap();
+        assert_eq!(buf_a, buf_b);
+    }
+
+    #[test]
+    fn iter_f32() {
+        let mut gen = Wdg::with_seed(0x84_2d_b6_e0_19_7f_c3_5a);
+        let mut expected = gen.clone();
+        let nans = gen
+            .iter_f32()
+            .take(1000)
+            .inspect(|&x| assert!(f32_exact_eq(x, expected.f32())))
+            .filter(|x| x.is_nan())
+            .count();
+        assert!(nans > 0);
+    }
+
+    #[test]
+    fn iter_f64() {
+        let mut gen = Wdg::with_seed(0xf7_50_3a_c9_6e_02_b4_d1);
+        let mut expected = gen.clone();
+        let subnormals = gen
+            .iter_f64()
+            .take(1000)
+            .inspect(|&x| assert!(f64_exact_eq(x, expected.f64())))
+            .filter(|x| x.is_subnormal())
+            .count();
+        assert!(subnormals > 0);
+    }
+
+    #[test]
+    fn weird_trait() {
+        fn same<T: Weird + PartialEq + core::fmt::Debug>(gen: &mut Wdg, f: impl Fn(&mut Wdg) -> T) {
+            let mut expected = gen.clone();
+            for _ in 0..1000 {
+                assert_eq!(T::weird(gen), f(&mut expected));
+            }
+        }
+
+        let mut gen = Wdg::with_seed(0x0c_a5_7e_d3_48_91_f6_2b);
+        same(&mut gen, Wdg::char);
+        same(&mut gen, Wdg::u8);
+        same(&mut gen, Wdg::i128);
+        same(&mut gen, Wdg::nonzero_usize);
+
+        let mut expected = gen.clone();
+        for _ in 0..1000 {
+            assert!(f32_exact_eq(f32::weird(&mut gen), expected.f32()));
+        }
+
+        let mut expected = gen.clone();
+        for _ in 0..100 {
+            let bytes: [u8; 16] = Weird::weird(&mut gen);
+            assert_eq!(bytes, core::array::from_fn(|_| expected.u8()));
+            let floats: [f64; 3] = Weird::weird(&mut gen);
+            for x in floats {
+                assert!(f64_exact_eq(x, expected.f64()));
+            }
+        }
+        let _: [i32; 0] = Weird::weird(&mut gen);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_string() {
+        let mut gen = Wdg::with_seed(0x2a_6f_c1_9d_53_e8_07_b4);
+        let mut had_empty = false;
+        let mut had_max = false;
+        let mut had_nul = false;
+        let mut had_combining = false;
+        let mut had_zwj = false;
+        let mut had_astral = false;
+        for _ in 0..1000 {
+            let string = gen.weird_string(16);
+            let len = string.chars().count();
+            assert!(len <= 16, "{:?}", string);
+            had_empty |= len == 0;
+            had_max |= len == 16;
+            had_nul |= string.contains('\0');
+            had_combining |= string.contains('\u{0301}');
+            had_zwj |= string.contains('\u{200D}');
+            had_astral |= string.chars().any(|c| c as u32 > 0xFFFF);
+        }
+        assert!(had_empty && had_max && had_nul && had_combining && had_zwj && had_astral);
+
+        assert_eq!(gen.weird_string(0), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_f32_from_decimal_string() {
+        let mut gen = Wdg::with_seed(0x62_ad_17_f9_c0_3e_85_b4);
+        let mut had_tie_to_even = false;
+        let mut had_long = false;
+        let mut had_subnormal = false;
+        let mut had_zero = false;
+        for _ in 0..10000 {
+            let (string, expected) = gen.weird_f32_from_decimal_string();
+            let parsed: f32 = string.parse().unwrap();
+            assert!(f32_exact_eq(parsed, expected), "{string} {expected:?}");
+
+            // a tie that rounded down, to the even value
+            let x = string.trim_start_matches('-').parse::<f64>().unwrap();
+            let up = float_utils::f32_step_ulps(expected.abs(), 1);
+            had_tie_to_even |= x == (expected.abs() as f64 + up as f64) / 2.0;
+            had_long |= string.len() > 500;
+            had_subnormal |= expected.is_subnormal();
+            had_zero |= expected == 0.0;
+        }
+        assert!(had_tie_to_even && had_long && had_subnormal && had_zero);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_ascii_string() {
+        let mut gen = Wdg::with_seed(0xb8_27_5d_e0_96_4a_f3_1c);
+        let mut had_max = false;
+        let mut had_control = false;
+        let mut had_del = false;
+        let mut had_printable = false;
+        for _ in 0..1000 {
+            let string = gen.weird_ascii_string(32);
+            assert!(string.len() <= 32);
+            assert!(string.bytes().all(|b| b < 0x80), "{:?}", string);
+            had_max |= string.len() == 32;
+            had_control |= string.contains(['\0', '\t', '\r', '\n']);
+            had_del |= string.contains('\x7F');
+            had_printable |= string.chars().any(|c| c.is_ascii_graphic());
+        }
+        assert!(had_max && had_control && had_del && had_printable);
+        assert_eq!(gen.weird_ascii_string(0), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_utf8_with_split_points() {
+        let mut gen = Wdg::with_seed(0x3a_94_e1_0c_7f_d6_b2_58);
+        let mut in_4_byte = 0;
+        let mut had_2_byte = false;
+        for _ in 0..1000 {
+            let (string, index) = gen.weird_utf8_with_split_points();
+            assert!(index < string.len());
+            assert!(!string.is_char_boundary(index), "{string:?} {index}");
+            let start = (0..index)
+                .rev()
+                .find(|&i| string.is_char_boundary(i))
+                .unwrap();
+            match string[start..].chars().next().unwrap().len_utf8() {
+                4 => in_4_byte += 1,
+                2 => had_2_byte = true,
+                _ => {}
+            }
+        }
+        assert!(in_4_byte > 500, "{in_4_byte}");
+        assert!(had_2_byte);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_json_string() {
+        let mut gen = Wdg::with_seed(0x58_c7_1a_e4_b0_3d_96_f2);
+        let mut had_max = false;
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let string = gen.weird_json_string(32);
+            let len = string.chars().count();
+            assert!(len <= 32);
+            had_max |= len == 32;
+            seen[0] |= string.contains('"');
+            seen[1] |= string.contains('\\');
+            seen[2] |= string.chars().any(|c| c < '\u{20}');
+            seen[3] |= string.contains('/');
+            seen[4] |= string.chars().any(|c| c as u32 > 0xFFFF);
+            seen[5] |= string.contains(['\u{2028}', '\u{2029}']);
+        }
+        assert!(had_max);
+        assert_eq!(seen, [true; 6]);
+        assert_eq!(gen.weird_json_string(0), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_cow_str() {
+        use alloc::borrow::Cow;
+
+        let mut gen = Wdg::with_seed(0xc3_5a_0f_e8_71_b6_2d_94);
+        let mut had_borrowed_empty = false;
+        let mut had_borrowed_long = false;
+        let mut had_owned = false;
+        for _ in 0..1000 {
+            match gen.weird_cow_str(16) {
+                Cow::Borrowed(s) => {
+                    had_borrowed_empty |= s.is_empty();
+                    had_borrowed_long |= s.chars().count() == 1024;
+                }
+                Cow::Owned(s) => {
+                    assert!(s.chars().count() <= 16);
+                    had_owned = true;
+                }
+            }
+        }
+        assert!(had_borrowed_empty && had_borrowed_long && had_owned);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn invalid_utf8() {
+        let mut gen = Wdg::with_seed(0xd6_83_1f_4c_b9_e0_72_a5);
+        let mut had_lone_continuation = false;
+        let mut had_truncated = false;
+        let mut had_overlong = false;
+        let mut had_surrogate = false;
+        for _ in 0..1000 {
+            let bytes = gen.invalid_utf8(16);
+            assert!(!bytes.is_empty() && bytes.len() <= 16);
+            assert!(std::str::from_utf8(&bytes).is_err(), "{:x?}", bytes);
+
+            // classify the errors with a minimal decoder
+            let mut i = 0;
+            while i < bytes.len() {
+                let continuations = match bytes[i] {
+                    0x00..=0x7F => 0,
+                    0x80..=0xBF => {
+                        had_lone_continuation = true;
+                        0
+                    }
+                    0xC0..=0xC1 => {
+                        had_overlong = true;
+                        1
+                    }
+                    0xC2..=0xDF => 1,
+                    0xED if bytes.get(i + 1).is_some_and(|b| *b >= 0xA0) => {
+                        had_surrogate = true;
+                        2
+                    }
+                    0xE0..=0xEF => 2,
+                    _ => 3,
+                };
+                i += 1;
+                for _ in 0..continuations {
+                    match bytes.get(i) {
+                        Some(0x80..=0xBF) => i += 1,
+                        _ => {
+                            had_truncated = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        assert!(had_lone_continuation && had_truncated && had_overlong && had_surrogate);
+
+        assert_eq!(gen.invalid_utf8(1).len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[ignore]
+    fn invalid_utf8_is_invalid() {
+        let mut gen = Wdg::with_seed(0x70_c4_9a_2e_f5_18_bd_63);
+        for i in 0..(1 << 24) {
+            let bytes = gen.invalid_utf8(i % 64 + 1);
+            assert!(std::str::from_utf8(&bytes).is_err(), "{}: {:x?}", i, bytes);
+        }
+    }
+
+    #[test]
+    fn u32_has_special_bits() {
+        let mut gen = Wdg::with_seed(0x85_2f_d9_64_0b_e3_a7_1c);
+        let mut had_alternating_a = false;
+        let mut had_alternating_5 = false;
+        for _ in 0..10000 {
+            let x = gen.u32();
+            had_alternating_a |= x == 0xAAAA_AAAA;
+            had_alternating_5 |= x == 0x5555_5555;
+        }
+        assert!(had_alternating_a && had_alternating_5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn duration_range() {
+        use std::time::Duration;
+
+        let mut gen = Wdg::with_seed(0xa3_6e_18_d7_f0_4b_c2_95);
+        let mut had_zero = false;
+        let mut had_max = false;
+        let mut had_carry = false;
+        let mut had_huge_secs = false;
+        for _ in 0..10000 {
+            let duration = gen.duration();
+            assert!(duration.subsec_nanos() < 1_000_000_000);
+            had_zero |= duration == Duration::ZERO;
+            had_max |= duration == Duration::MAX;
+            had_carry |= duration.subsec_nanos() == 999_999_999 && duration != Duration::MAX;
+            had_huge_secs |= duration.as_secs() >= u64::MAX - 2 && duration != Duration::MAX;
+        }
+        assert!(had_zero && had_max && had_carry && had_huge_secs);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ip_addr_range() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        let mut gen = Wdg::with_seed(0x5b_c8_e1_07_3f_a4_d6_92);
+        let mut had_unspecified_v4 = false;
+        let mut had_broadcast = false;
+        let mut had_loopback_v4 = false;
+        let mut had_link_local_v4 = false;
+        let mut had_unspecified_v6 = false;
+        let mut had_loopback_v6 = false;
+        let mut had_mapped = false;
+        let mut had_link_local_v6 = false;
+        for _ in 0..10000 {
+            match gen.ip_addr() {
+                IpAddr::V4(addr) => {
+                    had_unspecified_v4 |= addr == Ipv4Addr::UNSPECIFIED;
+                    had_broadcast |= addr == Ipv4Addr::BROADCAST;
+                    had_loopback_v4 |= addr.is_loopback();
+                    had_link_local_v4 |= addr.is_link_local();
+                }
+                IpAddr::V6(addr) => {
+                    had_unspecified_v6 |= addr == Ipv6Addr::UNSPECIFIED;
+                    had_loopback_v6 |= addr == Ipv6Addr::LOCALHOST;
+                    had_mapped |= addr.to_ipv4_mapped().is_some();
+                    had_link_local_v6 |= addr.segments()[0] == 0xfe80;
+                }
+            }
+        }
+        assert!(
+            had_unspecified_v4
+                && had_broadcast
+                && had_loopback_v4
+                && had_link_local_v4
+                && had_unspecified_v6
+                && had_loopback_v6
+                && had_mapped
+                && had_link_local_v6
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn socket_addr_range() {
+        let mut gen = Wdg::with_seed(0x28_7d_f4_b1_c6_0e_93_a5);
+        let mut ports = std::collections::HashSet::new();
+        for _ in 0..10000 {
+            ports.insert(gen.socket_addr().port());
+        }
+        for port in [0, 1, 80, 443, 65535] {
+            assert!(ports.contains(&port), "{}", port);
+        }
+    }
+
+    #[test]
+    fn choose() {
+        let mut gen = Wdg::with_seed(0xc9_04_7e_b2_58_fd_1a_63);
+        assert_eq!(gen.choose::<u8>(&[]), None);
+        assert_eq!(gen.choose_index(0), None);
+        for _ in 0..100 {
+            assert_eq!(gen.choose(&[42]), Some(&42));
+            assert_eq!(gen.choose_index(1), Some(0));
+        }
+
+        let items: std::vec::Vec<usize> = (0..100).collect();
+        let mut counts = [0; 100];
+        for _ in 0..10000 {
+            counts[*gen.choose(&items).unwrap()] += 1;
+        }
+        assert!(counts[0] > 3000 && counts[99] > 3000);
+        assert!(counts[1..99].iter().sum::<u32>() > 3000);
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut gen = Wdg::with_seed(0x16_a9_f3_5c_e0_87_2b_d4);
+        let mut items: std::vec::Vec<u32> = (0..100).collect();
+        gen.shuffle(&mut items);
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+        assert_ne!(items, sorted);
+        assert_eq!(sorted, (0..100).collect::<std::vec::Vec<_>>());
+
+        gen.shuffle::<u32>(&mut []);
+    }
+
+    #[test]
+    fn shuffle_adversarial() {
+        let mut gen = Wdg::with_seed(0x7f_20_c6_d8_4e_b1_95_3a);
+        let mut had_sorted = false;
+        let mut had_reversed = false;
+        let mut had_nearly_sorted = false;
+        for _ in 0..1000 {
+            let mut items: std::vec::Vec<u32> = (0..100).collect();
+            gen.shuffle_adversarial(&mut items);
+            let misplaced = items
+                .iter()
+                .enumerate()
+                .filter(|(i, x)| *i as u32 != **x)
+                .count();
+            had_sorted |= misplaced == 0;
+            had_reversed |= items.iter().rev().copied().eq(0..100);
+            had_nearly_sorted |= (1..=6).contains(&misplaced);
+
+            items.sort_unstable();
+            assert!(items.iter().copied().eq(0..100));
+        }
+        assert!(had_sorted && had_reversed && had_nearly_sorted);
+
+        gen.shuffle_adversarial::<u32>(&mut []);
+        gen.shuffle_adversarial(&mut [1]);
+    }
+
+    #[test]
+    fn weird_option() {
+        let mut gen = Wdg::with_seed(0xe8_3a_51_c0_9f_26_b7_4d);
+        let mut nones = 0;
+        for _ in 0..10000 {
+            nones += gen.weird_option(Wdg::f32).is_none() as u32;
+        }
+        assert!((3500..4500).contains(&nones), "{}", nones);
+    }
+
+    #[test]
+    fn weird_result() {
+        let mut gen = Wdg::with_seed(0x4d_f6_0b_93_27_e1_a8_5c);
+        let mut errs = 0;
+        for _ in 0..10000 {
+            errs += gen.weird_result(Wdg::u8, Wdg::i64).is_err() as u32;
+        }
+        assert!((5500..6500).contains(&errs), "{}", errs);
+    }
+
+    #[cfg(feature = "half")]
+    macro_rules! half {
+        ($($t:ident),+ $(,)?) => {
+            $(
+                half_inner!($t);
+            )+
+        };
+    }
+
+    #[cfg(feature = "half")]
+    macro_rules! half_inner {
+        ($t:ident) => {
+            paste! {
+                #[test]
+                fn [<nan_ $t _range>]() {
+                    let mut gen = Wdg::with_seed(0x93_c0_5e_a7_1d_f8_24_6b);
+                    let mut coverage: u16 = 0;
+                    for _ in 0..10000 {
+                        let num = gen.[<nan_ $t>]();
+                        assert!(num.is_nan(), "{:016b}", num.to_bits());
+                        coverage |= num.to_bits();
+                    }
+                    assert_eq!(coverage, u16::MAX, "{:016b}", coverage);
+                }
+
+                #[test]
+                fn [<subnormal_ $t _range>]() {
+                    let mut gen = Wdg::with_seed(0x2c_71_e9_b4_06_da_58_3f);
+                    let mut coverage: u16 = half::$t::INFINITY.to_bits();
+                    for _ in 0..10000 {
+                        let num = gen.[<subnormal_ $t>]();
+                        assert!(!num.is_normal() && num.is_finite() && num.to_bits() << 1 != 0, "{:016b}", num.to_bits());
+                        coverage |= num.to_bits();
+                    }
+                    assert_eq!(coverage, u16::MAX, "{:016b}", coverage);
+                }
+
+                #[test]
+                fn [<normal_ $t _range>]() {
+                    let mut gen = Wdg::with_seed(0xb8_4f_12_d3_7a_e6_c0_95);
+                    let mut coverage: u16 = 0;
+                    for _ in 0..10000 {
+                        let num = gen.[<normal_ $t>]();
+                        assert!(num.is_normal(), "{:016b}", num.to_bits());
+                        coverage |= num.to_bits();
+                    }
+                    assert_eq!(coverage, u16::MAX, "{:016b}", coverage);
+                }
+
+                #[test]
+                fn [<$t _range>]() {
+                    let mut gen = Wdg::with_seed(0x5d_a2_87_3c_f1_0e_b9_6a);
+                    let mut had_normal = false;
+                    let mut had_subnormal = false;
+                    let mut had_nan = false;
+                    let mut had_infinite = false;
+                    let mut had_neg_zero = false;
+                    for _ in 0..10000 {
+                        let num = gen.$t();
+                        had_normal |= num.is_normal();
+                        had_subnormal |= !num.is_normal() && num.is_finite() && num.to_bits() << 1 != 0;
+                        had_nan |= num.is_nan();
+                        had_infinite |= num.is_infinite();
+                        had_neg_zero |= num.to_bits() == half::$t::NEG_ZERO.to_bits();
+                    }
+                    assert!(had_normal && had_subnormal && had_nan && had_infinite && had_neg_zero);
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "half")]
+    half!(f16, bf16);
+
+    #[test]
+    fn special_f32_values() {
+        let values: std::vec::Vec<f32> = Wdg::special_f32_values().collect();
+        #[cfg(feature = "alloc")]
+        assert_eq!(Wdg::all_special_f32().len(), values.len());
+        #[cfg(feature = "alloc")]
+        assert!(Wdg::all_special_f32()
+            .into_iter()
+            .zip(values.iter().copied())
+            .all(|(a, b)| f32_exact_eq(a, b)));
+        assert_eq!(values.len(), 12);
+        assert!(values.iter().any(|&v| f32_exact_eq(v, -0.0)));
+        assert!(values.iter().any(|&v| f32_exact_eq(v, f32::NEG_INFINITY)));
+
+        let mut seen = [false; 12];
+        let mut gen = Wdg::with_seed(0x0a_d5_63_9e_b4_2f_c7_18);
+        for _ in 0..10000 {
+            let x = gen.special_f32();
+            let i = values.iter().position(|&v| f32_exact_eq(v, x)).unwrap();
+            seen[i] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn special_f64_values() {
+        let values: std::vec::Vec<f64> = Wdg::special_f64_values().collect();
+        #[cfg(feature = "alloc")]
+        assert_eq!(Wdg::all_special_f64().len(), values.len());
+        #[cfg(feature = "alloc")]
+        assert!(Wdg::all_special_f64()
+            .into_iter()
+            .zip(values.iter().copied())
+            .all(|(a, b)| f64_exact_eq(a, b)));
+        assert_eq!(values.len(), 12);
+        assert!(values.iter().any(|&v| f64_exact_eq(v, -0.0)));
+        assert!(values.iter().any(|&v| f64_exact_eq(v, f64::NEG_INFINITY)));
+
+        let mut seen = [false; 12];
+        let mut gen = Wdg::with_seed(0x8e_31_f7_4a_06_bc_d2_95);
+        for _ in 0..10000 {
+            let x = gen.special_f64();
+            let i = values.iter().position(|&v| f64_exact_eq(v, x)).unwrap();
+            seen[i] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn step_ulps() {
+        use crate::float_utils::{f32_step_ulps, f64_step_ulps};
+
+        let smallest = f32::from_bits(1);
+        assert!(f32_exact_eq(f32_step_ulps(0.0, 1), smallest));
+        assert!(f32_exact_eq(f32_step_ulps(-0.0, 1), smallest));
+        assert!(f32_exact_eq(f32_step_ulps(0.0, -1), -smallest));
+        assert!(f32_exact_eq(f32_step_ulps(smallest, -2), -smallest));
+        assert!(f32_exact_eq(f32_step_ulps(-smallest, 1), 0.0));
+        assert!(f32_exact_eq(f32_step_ulps(1.0, 1), 1.0 + f32::EPSILON));
+        assert!(f32_exact_eq(f32_step_ulps(f32::MAX, 1), f32::INFINITY));
+        assert!(f32_exact_eq(f32_step_ulps(f32::MAX, 5), f32::INFINITY));
+        assert!(f32_exact_eq(f32_step_ulps(f32::INFINITY, -1), f32::MAX));
+        assert!(f32_exact_eq(f32_step_ulps(f32::MIN, -1), f32::NEG_INFINITY));
+        assert!(f32_step_ulps(f32::NAN, 1).is_nan());
+
+        let smallest = f64::from_bits(1);
+        assert!(f64_exact_eq(f64_step_ulps(0.0, 1), smallest));
+        assert!(f64_exact_eq(f64_step_ulps(-0.0, -1), -smallest));
+        assert!(f64_exact_eq(f64_step_ulps(smallest, -2), -smallest));
+        assert!(f64_exact_eq(f64_step_ulps(1.0, 1), 1.0 + f64::EPSILON));
+        assert!(f64_exact_eq(f64_step_ulps(f64::MAX, 1), f64::INFINITY));
+        assert!(f64_exact_eq(f64_step_ulps(f64::MIN, -3), f64::NEG_INFINITY));
+        assert!(f64_step_ulps(f64::NAN, -1).is_nan());
+    }
+
+    #[test]
+    fn ulp_neighbor_f32() {
+        let mut gen = Wdg::with_seed(0x39_ce_72_b0_5f_14_a8_d6);
+        let mut had_negative = false;
+        let mut had_positive = false;
+        for _ in 0..1000 {
+            let x = gen.ulp_neighbor_f32(0.0);
+            assert!(x.abs() <= f32::from_bits(3), "{:032b}", x.to_bits());
+            had_negative |= x < 0.0;
+            had_positive |= x > 0.0;
+
+            let x = gen.ulp_neighbor_f32(1.0);
+            assert!((x - 1.0).abs() <= 3.0 * f32::EPSILON);
+        }
+        assert!(had_negative && had_positive);
+    }
+
+    #[test]
+    fn weird_near_f32() {
+        let mut gen = Wdg::with_seed(0xd2_8b_46_e9_01_7c_f3_5a);
+        let mut had_subnormal = false;
+        let mut had_near_one = false;
+        for _ in 0..10000 {
+            let x = gen.weird_near_f32();
+            assert!(!x.is_nan());
+            had_subnormal |= x.is_subnormal();
+            had_near_one |= x != 1.0 && (x - 1.0).abs() <= 3.0 * f32::EPSILON;
+        }
+        assert!(had_subnormal && had_near_one);
+    }
+
+    #[test]
+    fn weird_near_f64() {
+        let mut gen = Wdg::with_seed(0x6e_a1_0d_94_c3_58_2b_f7);
+        let mut had_subnormal = false;
+        let mut had_near_max = false;
+        for _ in 0..10000 {
+            let x = gen.weird_near_f64();
+            assert!(!x.is_nan());
+            had_subnormal |= x.is_subnormal();
+            had_near_max |= x != f64::MAX && x.is_finite() && x > f64::MAX / 2.0;
+        }
+        assert!(had_subnormal && had_near_max);
+    }
+
+    #[test]
+    fn weird_len() {
+        let mut gen = Wdg::with_seed(0x1d_86_e9_3f_a2_5c_b0_47);
+        let mut counts = [0; 5];
+        for _ in 0..10000 {
+            let len = gen.weird_len(100);
+            assert!(len <= 100);
+            match len {
+                0 => counts[0] += 1,
+                1 => counts[1] += 1,
+                2 => counts[2] += 1,
+                99 => counts[3] += 1,
+                100 => counts[4] += 1,
+                _ => {}
+            }
+            assert_eq!(gen.weird_len(0), 0);
+            assert!(gen.weird_len(1) <= 1);
+        }
+        for count in counts {
+            assert!(count > 1000, "{:?}", counts);
+        }
+        assert!(gen.weird_len(usize::MAX) > 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_vec() {
+        let mut gen = Wdg::with_seed(0x58_f3_2c_a0_e7_19_6d_b4);
+        let mut had_empty = false;
+        let mut had_max = false;
+        for _ in 0..100 {
+            let v = gen.weird_vec(1000, Wdg::f32);
+            assert!(v.len() <= 1000);
+            had_empty |= v.is_empty();
+            had_max |= v.len() == 1000;
+        }
+        assert!(had_empty && had_max);
+
+        assert!(gen.weird_vec(0, Wdg::u8).is_empty());
     }
 
-    /// Generates a random f32 denormal value.
-    ///
-    /// This generator covers all possible denormal values as specified in
-    /// IEEE-754.
-    pub fn subnormal_f32(&mut self) -> f32 {
-        let sign: u32 = self.0.u32(0..=1) << 31;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn interleave() {
+        let mut gen = Wdg::with_seed(0x09_e7_4b_d2_a6_3f_c8_15);
+        let mut had_alternating = false;
+        let mut had_burst = false;
+        for _ in 0..100 {
+            let v = gen.interleave(|_| 'a', |_| 'b', 64);
+            assert_eq!(v.len(), 64);
+            let switches = v.windows(2).filter(|w| w[0] != w[1]).count();
+            had_alternating |= switches > 16;
+            had_burst |= (1..4).contains(&switches);
+        }
+        assert!(had_alternating && had_burst);
 
-        // mantissa 00...00 is zero not denormal!
-        let mantissa: u32 = self.0.u32(1..(1 << 23));
+        assert!(gen.interleave(Wdg::u8, Wdg::u8, 0).is_empty());
+    }
 
-        let bits = sign | mantissa;
-        f32::from_bits(bits)
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_bool_run() {
+        let mut gen = Wdg::with_seed(0x5b_a3_e8_17_c4_6d_f0_92);
+        let mut had_all_true = false;
+        let mut had_alternating = false;
+        let mut had_runs = false;
+        for _ in 0..100 {
+            let v = gen.weird_bool_run(32);
+            assert_eq!(v.len(), 32);
+            let switches = v.windows(2).filter(|w| w[0] != w[1]).count();
+            had_all_true |= v.iter().all(|&b| b);
+            had_alternating |= switches == 31;
+            had_runs |= (1..4).contains(&switches);
+        }
+        assert!(had_all_true && had_alternating && had_runs);
+
+        assert!(gen.weird_bool_run(0).is_empty());
     }
 
-    /// Generates a random f64 denormal value.
-    ///
-    /// This generator covers all possible denormal values as specified in
-    /// IEEE-754.
-    pub fn subnormal_f64(&mut self) -> f64 {
-        let sign: u64 = self.0.u64(0..=1) << 63;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn weird_lines() {
+        let mut gen = Wdg::with_seed(0x3f_c8_05_e2_9a_71_d4_6b);
+        let mut had_mixed = false;
+        for _ in 0..100 {
+            let text = gen.weird_lines(10);
+            let has_crlf = text.contains("\r\n");
+            let has_lone_lf = text.replace("\r\n", "").replace("\n\r", "").contains('\n');
+            had_mixed |= has_crlf && has_lone_lf;
+        }
+        assert!(had_mixed);
 
-        // mantissa 00...00 is zero not denormal!
-        let mantissa: u64 = self.0.u64(1..(1 << 52));
+        assert_eq!(gen.weird_lines(0), "");
+    }
 
-        let bits = sign | mantissa;
-        f64::from_bits(bits)
+    #[test]
+    fn reset_to() {
+        let mut gen = Wdg::with_seed(0x5e_92_c1_7a_04_bd_38_f6);
+        let first = gen.0.u64(..);
+        gen.0.u64(..);
+        gen.reset_to(0x5e_92_c1_7a_04_bd_38_f6);
+        assert_eq!(gen.0.u64(..), first);
     }
 
-    /// Generate a random f32 normal value
-    pub fn normal_f32(&mut self) -> f32 {
-        let sign: u32 = self.0.u32(0..=1) << 31;
+    #[cfg(feature = "std")]
+    #[test]
+    fn reseed_from_entropy() {
+        let mut gen = Wdg::with_seed(0x71_e4_0b_c9_36_af_d2_58);
+        gen.reseed_from_entropy();
+        let a = gen.0.u64(..);
+        gen.reseed_from_entropy();
+        let b = gen.0.u64(..);
+        assert_ne!(a, b);
+    }
 
-        // careful with this range, all zeros and all ones are not normal
-        let exponent: u32 = self.0.u32(0b0000_0001..=0b1111_1110) << 23;
+    #[test]
+    fn sign_constrained_f32() {
+        let mut gen = Wdg::with_seed(0x2c_9f_e1_46_b8_0d_73_a5);
+        let mut had_subnormal = false;
+        let mut had_inf = false;
+        let mut had_zero = false;
+        for _ in 0..1000 {
+            let x = gen.positive_f32();
+            assert!(x > 0.0, "{x:?}");
+            had_subnormal |= x.is_subnormal();
+            had_inf |= x == f32::INFINITY;
 
-        let mantissa: u32 = self.0.u32(0..=(1 << 23));
-        let bits = sign | exponent | mantissa;
-        f32::from_bits(bits)
+            let x = gen.negative_f32();
+            assert!(x < 0.0, "{x:?}");
+
+            let x = gen.non_negative_f32();
+            assert!(x >= 0.0 && x.is_sign_positive(), "{x:?}");
+            had_zero |= x == 0.0;
+
+            let x = gen.non_positive_f32();
+            assert!(x <= 0.0 && x.is_sign_negative(), "{x:?}");
+        }
+        assert!(had_subnormal);
+        assert!(had_inf);
+        assert!(had_zero);
     }
 
-    /// Generate a random f64 normal value
-    pub fn normal_f64(&mut self) -> f64 {
-        let sign: u64 = self.0.u64(0..=1) << 63;
+    #[test]
+    fn sign_constrained_f64() {
+        let mut gen = Wdg::with_seed(0x84_d3_6e_1b_f0_a9_25_c7);
+        let mut had_subnormal = false;
+        let mut had_inf = false;
+        let mut had_zero = false;
+        for _ in 0..1000 {
+            let x = gen.positive_f64();
+            assert!(x > 0.0, "{x:?}");
+            had_subnormal |= x.is_subnormal();
+            had_inf |= x == f64::INFINITY;
 
-        // careful with this range, all zeros and all ones are not normal
-        let exponent: u64 = self.0.u64(0b000_0000_0001..=0b111_1111_1110) << 52;
+            let x = gen.negative_f64();
+            assert!(x < 0.0, "{x:?}");
 
-        let mantissa: u64 = self.0.u64(0..=(1 << 52));
-        let bits = sign | exponent | mantissa;
-        f64::from_bits(bits)
+            let x = gen.non_negative_f64();
+            assert!(x >= 0.0 && x.is_sign_positive(), "{x:?}");
+            had_zero |= x == 0.0;
+
+            let x = gen.non_positive_f64();
+            assert!(x <= 0.0 && x.is_sign_negative(), "{x:?}");
+        }
+        assert!(had_subnormal);
+        assert!(had_inf);
+        assert!(had_zero);
     }
 
-    /// Generate a random f32 "special" value
-    ///
-    /// A special value is what I call specific float values that are unique and
-    /// are pretty much impossible to generate by chance, and have some unusual
-    /// properties.
-    pub fn special_f32(&mut self) -> f32 {
-        match self.0.u8(0..=11) {
-            0 => 0.0,
-            1 => -0.0,
-            2 => f32::INFINITY,
-            3 => -f32::INFINITY,
-            4 => 1.0,
-            5 => -1.0,
-            6 => f32::MIN,
-            7 => f32::MAX,
-            8 => f32::MIN_POSITIVE,
-            9 => -f32::MIN_POSITIVE,
-            10 => f32::EPSILON,
-            11 => -f32::EPSILON,
-            _ => unreachable!(),
+    #[test]
+    fn signs() {
+        let mut gen = Wdg::with_seed(0xe6_2b_93_d0_5f_a7_1c_48);
+        let mut had_pos_zero = false;
+        let mut had_neg_zero = false;
+        let mut had_pos = false;
+        let mut had_neg = false;
+        for _ in 0..100 {
+            let x = gen.signed_zero_f64();
+            assert_eq!(x, 0.0);
+            had_pos_zero |= f64_exact_eq(x, 0.0);
+            had_neg_zero |= f64_exact_eq(x, -0.0);
+
+            let x = gen.sign_f64();
+            assert!(x == 1.0 || x == -1.0, "{x:?}");
+            had_pos |= x == 1.0;
+            had_neg |= x == -1.0;
+
+            assert_eq!(gen.signed_zero_f32(), 0.0);
+            assert_eq!(gen.sign_f32().abs(), 1.0);
         }
+        assert!(had_pos_zero && had_neg_zero && had_pos && had_neg);
     }
 
-    /// Generate a random f64 "special" value
-    ///
-    /// A special value is what I call specific float values that are unique and
-    /// are pretty much impossible to generate by chance, and have some unusual
-    /// properties.
-    pub fn special_f64(&mut self) -> f64 {
-        match self.0.u8(0..=11) {
-            0 => 0.0,
-            1 => -0.0,
-            2 => f64::INFINITY,
-            3 => -f64::INFINITY,
-            4 => 1.0,
-            5 => -1.0,
-            6 => f64::MIN,
-            7 => f64::MAX,
-            8 => f64::MIN_POSITIVE,
-            9 => -f64::MIN_POSITIVE,
-            10 => f64::EPSILON,
-            11 => -f64::EPSILON,
-            _ => unreachable!(),
+    #[test]
+    fn sample_f32_categories() {
+        let mut gen = Wdg::with_seed(0xe3_57_0a_c4_9d_28_b6_f1);
+        let counts = gen.sample_f32_categories(10000);
+        assert_eq!(counts.total(), 10000);
+        for count in [counts.normal, counts.subnormal, counts.nan, counts.special] {
+            assert!((2000..3000).contains(&count), "{counts:?}");
         }
+
+        assert_eq!(gen.sample_f32_categories(0), FloatCategoryCounts::default());
     }
 
-    /// Generate a random f32, such that special or problematic values are much
-    /// more common than normal.
-    ///
-    /// The distribution is not statistically useful, but it ensures that all edge-case
-    /// values get a fair chance of being generated. This is better than using a regular
-    /// random number generator, because in the vast majority of cases, a random number
-    /// generator will generate perfectly regular and well-behaved values, and certain
-    /// values, like `INFINITY` and `NAN` may be impossible to generate.
-    ///
-    /// The distribution is as follows:
-    /// - 25% normal values
-    /// - 25% subnormal values
-    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
-    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
-    pub fn f32(&mut self) -> f32 {
-        match self.0.u8(0..4) {
-            0 => self.normal_f32(),
-            1 => self.subnormal_f32(),
-            2 => self.nan_f32(),
-            3 => self.special_f32(),
-            _ => unreachable!(),
+    #[test]
+    fn from_seed_conversions() {
+        let seed = 0x0f_b2_6d_e8_43_a9_17_5c_u64;
+        let mut a: Wdg = seed.into();
+        let mut b: Wdg = seed.to_le_bytes().into();
+        let mut c = Wdg::with_seed(seed);
+        assert_eq!(a.get_seed(), c.get_seed());
+        assert_eq!(b.get_seed(), c.get_seed());
+        for _ in 0..100 {
+            let x = c.u64();
+            assert_eq!(a.u64(), x);
+            assert_eq!(b.u64(), x);
         }
+
+        let gen = Wdg::from([1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(gen.get_seed(), 1);
     }
 
-    /// Generate a random f64, such that special or problematic values are much
-    /// more common than normal.
-    ///
-    /// The distribution is not statistically useful, but it ensures that all edge-case
-    /// values get a fair chance of being generated. This is better than using a regular
-    /// random number generator, because in the vast majority of cases, a random number
-    /// generator will generate perfectly regular and well-behaved values, and certain
-    /// values, like `INFINITY` and `NAN` may be impossible to generate.
-    ///
-    /// The distribution is as follows:
-    /// - 25% normal values
-    /// - 25% subnormal values
-    /// - 25% `NAN` values, including all possible payloads, quiet and signaling `NAN`.
-    /// - 25% "special" values, i.e. unique values with special properties such as `INFINITY` and `-0.0`
-    pub fn f64(&mut self) -> f64 {
-        match self.0.u8(0..4) {
-            0 => self.normal_f64(),
-            1 => self.subnormal_f64(),
-            2 => self.nan_f64(),
-            3 => self.special_f64(),
-            _ => unreachable!(),
+    #[test]
+    fn preserves_signaling_nan() {
+        let mut gen = Wdg::with_seed(0x6b_d1_48_f3_0e_a7_92_5c);
+        for _ in 0..100 {
+            assert!(gen.preserves_signaling_nan_f32(|x| x));
+            assert!(gen.preserves_signaling_nan_f64(|x| x));
+            assert!(!gen.preserves_signaling_nan_f32(|_| f32::NAN));
+            assert!(!gen.preserves_signaling_nan_f64(|_| 0.0));
         }
     }
 
-    uint!(self, [u8, u16, u32, u64, u128, usize]);
+    #[cfg(feature = "std")]
+    #[test]
+    fn weird_path() {
+        let mut gen = Wdg::with_seed(0x4e_a9_13_d7_6c_f0_85_2b);
+        let mut had_parent = false;
+        let mut had_long_name = false;
+        let mut had_trailing_separator = false;
+        let mut had_non_utf8 = false;
+        for _ in 0..1000 {
+            let path = gen.weird_path();
+            had_parent |= path
+                .components()
+                .any(|c| c == std::path::Component::ParentDir);
+            had_long_name |= path.iter().any(|c| (254..=256).contains(&c.len()));
+            had_trailing_separator |= path.as_os_str().to_string_lossy().ends_with('/');
+            had_non_utf8 |= path.to_str().is_none();
+        }
+        assert!(had_parent && had_long_name && had_trailing_separator);
+        if cfg!(any(unix, windows)) {
+            assert!(had_non_utf8);
+        }
+    }
 
-    int!(self, [i8, i16, i32, i64, i128, isize]);
-}
+    #[test]
+    fn fill() {
+        let mut gen = Wdg::with_seed(0x8d_26_f5_a0_c3_7e_19_b4);
+        let mut expected = gen.clone();
+        let mut buf = [0i32; 100];
+        gen.fill(&mut buf);
+        for x in buf {
+            assert_eq!(x, expected.i32());
+        }
 
-#[cfg(test)]
-mod test_unit {
-    extern crate std;
+        let seed = gen.get_seed();
+        gen.fill::<f64>(&mut []);
+        assert_eq!(gen.get_seed(), seed);
+    }
 
-    use super::*;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn extend_weird() {
+        let mut gen = Wdg::with_seed(0x5e_b1_07_c4_9a_f2_63_d8);
+        let mut expected = gen.clone();
+        let mut v: std::vec::Vec<u16> = std::vec![1, 2, 3];
+        gen.extend_weird::<u16, _>(&mut v, 100);
+        assert_eq!(v.len(), 103);
+        assert_eq!(v[..3], [1, 2, 3]);
+        for &x in &v[3..] {
+            assert_eq!(x, expected.u16());
+        }
+
+        let mut deque = std::collections::VecDeque::<f32>::new();
+        gen.extend_weird::<f32, _>(&mut deque, 10);
+        assert_eq!(deque.len(), 10);
+    }
 
     #[test]
-    fn nan_f32() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(gen.nan_f32().is_nan());
+    fn weird_bool() {
+        let mut gen = Wdg::with_seed(0x7a_c2_19_e5_40_db_8f_36);
+        let mut trues = 0;
+        for _ in 0..10000 {
+            assert!(!gen.weird_bool(0.0));
+            assert!(!gen.weird_bool(-1.0));
+            assert!(!gen.weird_bool(f64::NAN));
+            assert!(gen.weird_bool(1.0));
+            assert!(gen.weird_bool(f64::INFINITY));
+            trues += gen.weird_bool(0.25) as u32;
+        }
+        assert!((2000..3000).contains(&trues), "{trues}");
+
+        let trues = (0..10000).filter(|_| gen.bool()).count();
+        assert!((4500..5500).contains(&trues), "{trues}");
     }
 
     #[test]
-    fn nan_f64() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(gen.nan_f64().is_nan());
+    fn canonical_nan() {
+        assert_eq!(Wdg::canonical_nan_f32().to_bits(), f32::NAN.to_bits());
+        assert_eq!(Wdg::canonical_nan_f64().to_bits(), f64::NAN.to_bits());
+        assert!(float_utils::f32_is_quiet_nan(Wdg::canonical_nan_f32()));
+        assert!(float_utils::f64_is_quiet_nan(Wdg::canonical_nan_f64()));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn subnormal_f32() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(gen.subnormal_f32().is_subnormal());
+    fn write_weird_bytes() {
+        use std::io::{self, Write};
+
+        let mut gen = Wdg::with_seed(0x93_d4_61_0b_f8_2a_c7_5e);
+        for n in [0, 1, 4095, 4096, 4097, 100_000] {
+            let mut out = std::vec::Vec::new();
+            gen.write_weird_bytes(&mut out, n).unwrap();
+            assert_eq!(out.len(), n);
+        }
+
+        /// Accepts a few bytes, then fails.
+        struct Failing(usize);
+        impl Write for Failing {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::other("full"));
+                }
+                let n = buf.len().min(self.0);
+                self.0 -= n;
+                Ok(n)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let err = gen
+            .write_weird_bytes(&mut Failing(5000), 10_000)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "full");
+        gen.write_weird_bytes(&mut Failing(0), 0).unwrap();
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn subnormal_f64() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(gen.subnormal_f64().is_subnormal());
+    fn weird_duration_pair() {
+        use std::time::Duration;
+
+        let mut gen = Wdg::with_seed(0x0e_73_c9_a5_4d_f1_28_b6);
+        let mut underflows = 0;
+        let mut had_one_nano = false;
+        let mut had_equal = false;
+        let mut had_max = false;
+        let mut had_greater = false;
+        for _ in 0..1000 {
+            let (a, b) = gen.weird_duration_pair();
+            underflows += a.checked_sub(b).is_none() as u32;
+            had_one_nano |= b.checked_sub(a) == Some(Duration::from_nanos(1));
+            had_equal |= a == b && a != Duration::MAX;
+            had_max |= a == Duration::MAX && b == Duration::MAX;
+            had_greater |= a > b;
+        }
+        assert!(underflows > 300, "{underflows}");
+        assert!(had_one_nano && had_equal && had_max && had_greater);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn normal_f32() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(!gen.normal_f32().is_subnormal());
+    fn system_time() {
+        use std::time::UNIX_EPOCH;
+
+        let mut gen = Wdg::with_seed(0xb5_3c_e8_17_a4_6f_d0_29);
+        let mut had_epoch = false;
+        let mut had_before_epoch = false;
+        let mut had_year_9999 = false;
+        let mut had_latest = false;
+        for _ in 0..1000 {
+            let time = gen.system_time();
+            had_epoch |= time == UNIX_EPOCH;
+            had_before_epoch |= time < UNIX_EPOCH;
+            had_year_9999 |= time
+                .duration_since(UNIX_EPOCH)
+                .is_ok_and(|d| d.as_secs() == 253_402_300_799);
+            had_latest |= time
+                .checked_add(std::time::Duration::from_secs(1))
+                .is_none();
+        }
+        assert!(had_epoch && had_before_epoch && had_year_9999 && had_latest);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn normal_f64() {
-        let mut gen = Wdg::with_seed(0);
-        assert!(!gen.normal_f64().is_subnormal());
+    fn weird_grapheme() {
+        let mut gen = Wdg::with_seed(0x1d_84_f7_3a_c9_50_e2_6b);
+        let mut had_stacked = false;
+        let mut had_flag = false;
+        let mut had_zwj = false;
+        for _ in 0..1000 {
+            let grapheme = gen.weird_grapheme();
+            let chars = grapheme.chars().count();
+            assert!(chars >= 2, "{grapheme:?}");
+            had_stacked |= grapheme
+                .chars()
+                .filter(|c| ('\u{0300}'..='\u{036F}').contains(c))
+                .count()
+                >= 10;
+            had_flag |= chars == 2
+                && grapheme
+                    .chars()
+                    .all(|c| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c));
+            had_zwj |= grapheme.contains('\u{200D}');
+        }
+        assert!(had_stacked && had_flag && had_zwj);
+
+        assert_eq!(gen.weird_graphemes(0), "");
     }
-}
 
-#[cfg(test)]
-mod test_fuzz {
-    // fuzzing tests, they may take a while to run. Shouldn't last more than
-    // a minute per test (or I'll get impatient/cargo will complain)
+    #[test]
+    fn char_in_range() {
+        let mut gen = Wdg::with_seed(0x65_b0_d9_2e_47_f3_8c_1a);
+        let mut had_lo = false;
+        let mut had_hi = false;
+        let mut had_boundary = false;
+        let mut had_above_gap = false;
+        for _ in 0..10000 {
+            let c = gen.char_in_range('\u{D000}', '\u{E100}');
+            assert!(('\u{D000}'..='\u{E100}').contains(&c));
+            had_lo |= c == '\u{D000}';
+            had_hi |= c == '\u{E100}';
+            had_boundary |= c == '\u{D7FF}' || c == '\u{E000}';
+            had_above_gap |= c > '\u{E000}' && c < '\u{E100}';
 
-    extern crate std;
+            assert_eq!(gen.char_in_range('x', 'x'), 'x');
 
-    use crate::float_utils::{f32_exact_eq, f64_exact_eq};
+            let c = gen.char_in_range('a', 'z');
+            assert!(c.is_ascii_lowercase());
+        }
+        assert!(had_lo && had_hi && had_boundary && had_above_gap);
+    }
 
-    use super::*;
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn char_in_range_empty() {
+        let mut gen = Wdg::with_seed(0x0d_e6_39_c1_a4_72_5f_b8);
+        gen.char_in_range('z', 'a');
+    }
 
-    // TODO: all seeds here should be picked at random from RANDOM.org
+    #[test]
+    fn weird_enum_index() {
+        let mut gen = Wdg::with_seed(0x49_f1_a7_0c_e3_5d_b6_82);
+        let mut valid = 0;
+        let mut had_last = false;
+        let mut had_first_invalid = false;
+        let mut had_max = false;
+        for _ in 0..10000 {
+            let i = gen.weird_enum_index(5);
+            valid += (i < 5) as u32;
+            had_last |= i == 4;
+            had_first_invalid |= i == 5;
+            had_max |= i == u32::MAX;
+
+            gen.weird_enum_index(0);
+            gen.weird_enum_index(u32::MAX);
+        }
+        assert!((4000..6000).contains(&valid), "{valid}");
+        assert!(had_last && had_first_invalid && had_max);
+    }
 
     #[test]
-    #[ignore]
-    fn nan_f32_is_nan() {
-        let mut gen = Wdg::with_seed(0x0b_65_58_2b_4e_d8_20_fe);
-        for i in 0..(1 << 30) {
-            let num = gen.nan_f32();
-            assert!(num.is_nan(), "{}: {:032b}", i, num.to_bits());
+    fn weird_index_into() {
+        let mut gen = Wdg::with_seed(0xd2_5e_08_b3_7c_a1_f4_69);
+        let v = [1u8, 2, 3, 4, 5];
+        let mut valid = 0;
+        let mut had_last = false;
+        let mut had_first_invalid = false;
+        let mut had_max = false;
+        for _ in 0..10000 {
+            let i = gen.weird_index_into(v.len());
+            assert_eq!(v.get(i).is_some(), i < v.len());
+            valid += (i < v.len()) as u32;
+            had_last |= i == 4;
+            had_first_invalid |= i == 5;
+            had_max |= i == usize::MAX;
+
+            gen.weird_index_into(0);
+            gen.weird_index_into(usize::MAX);
         }
+        assert!((4000..6000).contains(&valid), "{valid}");
+        assert!(had_last && had_first_invalid && had_max);
     }
 
     #[test]
-    #[ignore]
-    fn nan_f64_is_nan() {
-        let mut gen = Wdg::with_seed(0x36_44_3e_f8_40_af_6e_49);
-        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
-        //       way too many to guess the bad ones at random. Maybe do something
-        //       meta where you use this crate to fuzz itself?
-        for i in 0..1 << 30 {
-            let num = gen.nan_f64();
-            assert!(num.is_nan(), "{}: {:064b}", i, num.to_bits());
+    fn float_array() {
+        let mut gen = Wdg::with_seed(0x3e_07_b9_d4_52_ac_61_f8);
+        for _ in 0..100 {
+            let mut expected = gen.clone();
+            let array: [f32; 8] = gen.f32_array();
+            for x in array {
+                assert!(f32_exact_eq(x, expected.f32()));
+            }
+
+            let mut expected = gen.clone();
+            let array: [f64; 8] = gen.f64_array();
+            for x in array {
+                assert!(f64_exact_eq(x, expected.f64()));
+            }
         }
+
+        let empty: [f32; 0] = gen.f32_array();
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn nan_f32_range() {
-        let mut gen = Wdg::with_seed(0x29_21_f1_bd_8b_a9_c6_b6);
-        let mut coverage: u32 = 0b0;
+    fn rng_mut() {
+        let mut gen = Wdg::with_seed(0x8b_14_e7_c2_09_fa_56_3d);
+        let mut expected = fastrand::Rng::with_seed(0x8b_14_e7_c2_09_fa_56_3d);
+        assert_eq!(gen.rng_mut().usize(0..10), expected.usize(0..10));
+        assert_eq!(gen.as_rng().get_seed(), expected.get_seed());
+
+        // the weird generators continue from the same state
+        let x = gen.u64();
+        assert_eq!(x, Wdg(expected, FloatWeights::default()).u64());
+    }
+
+    #[test]
+    fn weird_f32_in() {
+        let mut gen = Wdg::with_seed(0xc1_58_e9_2f_a6_0b_d3_74);
+        let mut had_lo = false;
+        let mut had_hi = false;
+        let mut had_zero = false;
+        let mut had_subnormal = false;
+        let mut excluding_zero_lo = 0;
         for _ in 0..10000 {
-            let num = gen.nan_f32();
-            coverage |= num.to_bits();
-        }
+            let x = gen.weird_f32_in(-1.5, 1e3);
+            assert!((-1.5..=1e3).contains(&x), "{x:?}");
+            had_lo |= x == -1.5;
+            had_hi |= x == 1e3;
+            had_zero |= x == 0.0;
+            had_subnormal |= x.is_subnormal();
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+            let x = gen.weird_f32_in(f32::MAX, f32::MIN);
+            assert!(x.is_finite());
+
+            let x = gen.weird_f32_in(1.0, 2.0);
+            assert!((1.0..=2.0).contains(&x), "{x:?}");
+            excluding_zero_lo += (x == 1.0) as u32;
+
+            let x = gen.weird_f32_in(-2.0, -2.0);
+            assert_eq!(x, -2.0);
+        }
+        assert!(had_lo && had_hi && had_zero && had_subnormal);
+        // 0.0 and subnormals are out of range, so they must not be clamped
+        // to the lower bound, which is one of 5 equally likely cases
+        assert!(
+            (1500..2500).contains(&excluding_zero_lo),
+            "{excluding_zero_lo}"
+        );
     }
 
     #[test]
-    fn nan_f64_range() {
-        let mut gen = Wdg::with_seed(0x6f_35_67_53_e6_37_13_c3);
-        let mut coverage: u64 = 0b0;
+    fn weird_f64_in() {
+        let mut gen = Wdg::with_seed(0x52_9a_d6_0e_b8_f4_17_c3);
+        let mut had_lo = false;
+        let mut had_hi = false;
+        let mut had_zero = false;
+        let mut had_subnormal = false;
+        let mut excluding_zero_lo = 0;
         for _ in 0..10000 {
-            let num = gen.nan_f64();
-            coverage |= num.to_bits();
+            let x = gen.weird_f64_in(-1.5, 1e3);
+            assert!((-1.5..=1e3).contains(&x), "{x:?}");
+            had_lo |= x == -1.5;
+            had_hi |= x == 1e3;
+            had_zero |= x == 0.0;
+            had_subnormal |= x.is_subnormal();
+
+            let x = gen.weird_f64_in(f64::MAX, f64::MIN);
+            assert!(x.is_finite());
+
+            let x = gen.weird_f64_in(1.0, 2.0);
+            assert!((1.0..=2.0).contains(&x), "{x:?}");
+            excluding_zero_lo += (x == 1.0) as u32;
+
+            let x = gen.weird_f64_in(-2.0, -2.0);
+            assert_eq!(x, -2.0);
         }
+        assert!(had_lo && had_hi && had_zero && had_subnormal);
+        // 0.0 and subnormals are out of range, so they must not be clamped
+        // to the lower bound, which is one of 5 equally likely cases
+        assert!(
+            (1500..2500).contains(&excluding_zero_lo),
+            "{excluding_zero_lo}"
+        );
+    }
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    #[test]
+    fn weird_f64_near_integer() {
+        let mut gen = Wdg::with_seed(0x4c_19_a7_e3_8f_02_d6_5b);
+        let mut had_unsafe_i32 = false;
+        let mut had_unsafe_i64 = false;
+        let mut had_imprecise = false;
+        let mut had_half = false;
+        for _ in 0..10000 {
+            let x = gen.weird_f64_near_integer();
+            assert!(x.is_finite());
+            had_unsafe_i32 |= x > i32::MAX as f64 && x < i32::MAX as f64 + 1.0;
+            had_unsafe_i64 |= x == i64::MAX as f64;
+            had_imprecise |= x == (1u64 << 53) as f64 + 2.0;
+            had_half |= x.fract().abs() == 0.5;
+        }
+        assert!(had_unsafe_i32 && had_unsafe_i64 && had_imprecise && had_half);
     }
 
     #[test]
-    #[ignore]
-    fn subnoraml_f32_is_subnormal() {
-        let mut gen = Wdg::with_seed(0x52_58_4a_d1_55_e1_72_10);
-        for i in 0..(1 << 30) {
-            let num = gen.subnormal_f32();
-            assert!(num.is_subnormal(), "{}: {:032b}", i, num.to_bits());
+    fn weird_f64_angle() {
+        let mut gen = Wdg::with_seed(0xd2_6e_08_b3_f5_1a_94_7c);
+        let mut had_neg_zero = false;
+        let mut had_half_pi = false;
+        let mut had_worst_case = false;
+        let mut had_huge = false;
+        let mut had_multiple = false;
+        for _ in 0..10000 {
+            let x = gen.weird_f64_angle();
+
+            // reduce the multiples of π/2 with π/2 split in three parts, as in
+            // fdlibm, the products and first difference are exact
+            if (8.0..2e6).contains(&x.abs()) {
+                const PIO2_1: f64 = 1.5707963267341256;
+                const PIO2_2: f64 = 6.077100506303966e-11;
+                const PIO2_2T: f64 = 2.0222662487959506e-21;
+                let k = (x / core::f64::consts::FRAC_PI_2).round();
+                let distance = (x - k * PIO2_1) - k * PIO2_2 - k * PIO2_2T;
+                assert!(distance.abs() <= x.abs() * f64::EPSILON, "{x:?}");
+                had_multiple = true;
+            }
+
+            assert!(x.is_finite(), "{x:?}");
+            had_neg_zero |= float_utils::f64_exact_eq(x, -0.0);
+            had_half_pi |= x == core::f64::consts::FRAC_PI_2;
+            had_worst_case |= x == 6381956970095103.0 * 2f64.powi(797);
+            had_huge |= x.abs() >= 1e300;
         }
+        assert!(had_neg_zero && had_half_pi && had_worst_case && had_huge && had_multiple);
     }
 
     #[test]
-    #[ignore]
-    fn subnormal_f64_is_subnormal() {
-        let mut gen = Wdg::with_seed(0x2d_46_cc_c0_45_c5_ec_03);
-        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
-        //       way too many to guess the bad ones at random. Maybe do something
-        //       meta where you use this crate to fuzz itself?
-        for i in 0..1 << 30 {
-            let num = gen.subnormal_f64();
-            assert!(num.is_subnormal(), "{}: {:064b}", i, num.to_bits());
+    fn weird_pair_for_comparison() {
+        let mut gen = Wdg::with_seed(0x71_f0_c3_9a_2e_b5_48_d6);
+        let mut had_nans = false;
+        let mut had_zeros = false;
+        let mut had_inf_max = false;
+        let mut had_adjacent = false;
+        let mut had_f32_zeros = false;
+        for _ in 0..1000 {
+            let (a, b) = gen.weird_f64_pair_for_comparison();
+            had_nans |= a.is_nan() && b.is_nan();
+            had_zeros |= a == b && a.total_cmp(&b).is_ne();
+            had_inf_max |= a.max(b) == f64::INFINITY && a.min(b) == f64::MAX;
+            had_adjacent |= a.is_finite()
+                && b.is_finite()
+                && a != b
+                && float_utils::f64_step_ulps(a.min(b), 1) == a.max(b);
+
+            let (a, b) = gen.weird_f32_pair_for_comparison();
+            had_f32_zeros |= a == b && a.total_cmp(&b).is_ne();
         }
+        assert!(had_nans && had_zeros && had_inf_max && had_adjacent && had_f32_zeros);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn subnormal_f32_range() {
-        let mut gen = Wdg::with_seed(0x98_fb_6b_ef_ac_5d_81_f3);
-        let mut coverage: u32 = 0b1111_1111 << 23;
-        for _ in 0..10000 {
-            let num = gen.subnormal_f32();
-            coverage |= num.to_bits();
+    fn weird_f32_accumulation_sequence() {
+        let mut gen = Wdg::with_seed(0x1f_6a_c8_34_e2_9d_05_b7);
+        let mut modes = [0; 3];
+        for _ in 0..300 {
+            let v = gen.weird_f32_accumulation_sequence(100);
+            assert_eq!(v.len(), 100);
+            assert!(v.iter().all(|x| x.is_finite()));
+
+            // every mode starts with the big value, followed by tiny terms,
+            // except for the repeated blocks, which negate it right after
+            let big = v[0];
+            let tiny = &v[1..99];
+            let mode = if v[2] == -big {
+                2
+            } else if tiny.iter().all(|&x| x == tiny[0]) {
+                0
+            } else {
+                1
+            };
+            modes[mode] += 1;
+
+            // the naive sum loses every tiny term, whatever the mode
+            let bigs: f32 = v.iter().filter(|x| x.abs() == big).sum();
+            let naive: f32 = v.iter().sum();
+            assert_eq!(naive, bigs, "mode {mode}");
+
+            // the f64 sum is exact, as the terms are few binades apart
+            let exact: f64 = v.iter().map(|&x| x as f64).sum();
+            let tinies: f64 = exact - bigs as f64;
+            match mode {
+                0 => assert_eq!(tinies, tiny.len() as f64 * tiny[0] as f64),
+                1 => assert!(tiny.iter().any(|&x| x > 0.0) && tiny.iter().any(|&x| x < 0.0)),
+                2 => assert_eq!(tinies, 33.0 * v[1] as f64),
+                _ => unreachable!(),
+            }
         }
+        assert!(modes.iter().all(|&count| count > 50), "{modes:?}");
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+        assert!(gen.weird_f32_accumulation_sequence(0).is_empty());
+        assert_eq!(gen.weird_f32_accumulation_sequence(1).len(), 1);
+        assert_eq!(gen.weird_f32_accumulation_sequence(2).len(), 2);
     }
 
     #[test]
-    fn subnormal_f64_range() {
-        let mut gen = Wdg::with_seed(0x7a_07_58_14_f4_b8_2f_49);
-        let mut coverage: u64 = 0b111_1111_1111 << 52;
-        for _ in 0..10000 {
-            let num = gen.subnormal_f64();
-            coverage |= num.to_bits();
+    fn weird_float_bits() {
+        let mut gen = Wdg::with_seed(0xc5_38_0f_a1_e9_64_d2_7b);
+        let mut expected = gen.clone();
+        let mut had_signaling = false;
+        for _ in 0..1000 {
+            let bits = gen.weird_f32_bits();
+            assert_eq!(bits, expected.f32().to_bits());
+            assert_eq!(f32::from_bits(bits).to_bits(), bits);
+
+            let bits = gen.weird_f64_bits();
+            assert_eq!(bits, expected.f64().to_bits());
+            assert_eq!(f64::from_bits(bits).to_bits(), bits);
+            had_signaling |= f64_is_signaling_nan(f64::from_bits(bits));
         }
+        assert!(had_signaling);
+    }
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+    #[test]
+    fn float_covering() {
+        use float_utils::{classify_f32, classify_f64, FloatClass};
+
+        const CLASSES: [FloatClass; 8] = [
+            FloatClass::Normal,
+            FloatClass::Subnormal,
+            FloatClass::Zero,
+            FloatClass::NegZero,
+            FloatClass::Infinity,
+            FloatClass::NegInfinity,
+            FloatClass::QuietNan,
+            FloatClass::SignalingNan,
+        ];
+
+        let mut gen = Wdg::with_seed(0xa8_3e_f1_6c_05_d9_b2_47);
+        let mut seen_f32 = [false; 8];
+        let mut seen_f64 = [false; 8];
+        for i in 0..10000 {
+            let class = classify_f32(gen.f32_covering(i));
+            seen_f32[CLASSES.iter().position(|&c| c == class).unwrap()] = true;
+            let class = classify_f64(gen.f64_covering(i));
+            seen_f64[CLASSES.iter().position(|&c| c == class).unwrap()] = true;
+
+            // every block of 16 draws covers all classes
+            if i % 16 == 15 {
+                assert_eq!(seen_f32, [true; 8]);
+                assert_eq!(seen_f64, [true; 8]);
+                seen_f32 = [false; 8];
+                seen_f64 = [false; 8];
+            }
+        }
     }
 
     #[test]
-    #[ignore]
-    fn noraml_f32_is_not_subnormal() {
-        let mut gen = Wdg::with_seed(0x2c_fe_59_bb_7a_56_28_20);
-        for i in 0..(1 << 30) {
-            let num = gen.normal_f32();
-            assert!(!num.is_subnormal(), "{}: {:032b}", i, num.to_bits());
+    fn classify() {
+        use float_utils::{classify_f32, classify_f64, FloatClass};
+
+        let mut gen = Wdg::with_seed(0x17_d8_4c_a3_f5_2e_90_6b);
+        for _ in 0..1000 {
+            assert_eq!(classify_f32(gen.normal_f32()), FloatClass::Normal);
+            assert_eq!(classify_f64(gen.subnormal_f64()), FloatClass::Subnormal);
+            assert_eq!(classify_f32(gen.quiet_nan_f32()), FloatClass::QuietNan);
+            assert_eq!(
+                classify_f64(gen.signaling_nan_f64()),
+                FloatClass::SignalingNan
+            );
         }
+        assert_eq!(classify_f32(0.0), FloatClass::Zero);
+        assert_eq!(classify_f32(-0.0), FloatClass::NegZero);
+        assert_eq!(classify_f64(f64::INFINITY), FloatClass::Infinity);
+        assert_eq!(classify_f64(f64::NEG_INFINITY), FloatClass::NegInfinity);
+        assert_eq!(
+            std::format!("got {}", FloatClass::SignalingNan),
+            "got SignalingNan"
+        );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    #[ignore]
-    fn normal_f64_is_not_subnormal() {
-        let mut gen = Wdg::with_seed(0xa9_26_d1_d9_7b_d7_94_15);
-        // TODO: this test has poor coverage, there are 1 << 52 possible mantissas
-        //       way too many to guess the bad ones at random. Maybe do something
-        //       meta where you use this crate to fuzz itself?
-        for i in 0..1 << 30 {
-            let num = gen.normal_f64();
-            assert!(!num.is_subnormal(), "{}: {:064b}", i, num.to_bits());
+    fn weird_blob() {
+        let mut gen = Wdg::with_seed(0xf4_3b_86_d1_0a_c9_5e_27);
+        let mut had_empty = false;
+        let mut had_max = false;
+        let mut had_nan_le = false;
+        let mut had_nan_be = false;
+        for _ in 0..1000 {
+            let blob = gen.weird_blob(64, Endian::Little);
+            assert!(blob.len() <= 64);
+            had_empty |= blob.is_empty();
+            had_max |= blob.len() == 64;
+            had_nan_le |= blob
+                .windows(4)
+                .any(|w| f32::from_le_bytes(w.try_into().unwrap()).is_nan());
+
+            let blob = gen.weird_blob(64, Endian::Big);
+            had_nan_be |= blob
+                .windows(8)
+                .any(|w| f64::from_be_bytes(w.try_into().unwrap()).is_nan());
         }
+        assert!(had_empty && had_max && had_nan_le && had_nan_be);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn normal_f32_range() {
-        let mut gen = Wdg::with_seed(0x15_63_e3_11_09_cb_11_b5);
-        let mut coverage: u32 = 0;
-        for _ in 0..10000 {
-            let num = gen.normal_f32();
-            coverage |= num.to_bits();
+    fn bytes_with_pattern() {
+        let mut gen = Wdg::with_seed(0x93_0e_d7_5a_b4_21_fc_68);
+        let mut had_single_flip = false;
+        let mut had_many_flips = false;
+        for _ in 0..1000 {
+            let bytes = gen.bytes_with_pattern(100, 0x00);
+            assert_eq!(bytes.len(), 100);
+            let flips = bytes.iter().filter(|&&b| b != 0x00).count();
+            had_single_flip |= flips == 1;
+            had_many_flips |= flips > 25;
         }
+        assert!(had_single_flip && had_many_flips);
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u32::MAX, "{:032b}", coverage);
+        assert!(gen.bytes_with_pattern(0, 0xAA).is_empty());
+        assert_eq!(gen.bytes_with_pattern_density(64, 0xAA, 0.0), [0xAA; 64]);
+        assert!(gen
+            .bytes_with_pattern_density(64, 0xFF, 1.0)
+            .iter()
+            .all(|&b| b != 0xFF));
+
+        let bytes = gen.bytes_with_pattern_density(10000, 0x00, 0.2);
+        let flips = bytes.iter().filter(|&&b| b != 0x00).count();
+        assert!((1500..2500).contains(&flips), "{}", flips);
+        assert!(bytes.contains(&0xFF));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn normal_f64_range() {
-        let mut gen = Wdg::with_seed(0x56_e5_19_b1_47_f2_5e_0d);
-        let mut coverage: u64 = 0;
-        for _ in 0..10000 {
-            let num = gen.normal_f64();
-            coverage |= num.to_bits();
+    fn weird_matrix_f64() {
+        let mut gen = Wdg::with_seed(0xa4_0d_7e_c2_59_b8_13_f6);
+        let mut had_nan_in_weird = false;
+        for _ in 0..100 {
+            let m = gen.weird_matrix_f64(4, 5, MatrixMode::Weird);
+            assert_eq!(m.len(), 20);
+            had_nan_in_weird |= m.iter().any(|x| x.is_nan());
+
+            let m = gen.weird_matrix_f64(4, 5, MatrixMode::SingleNan);
+            assert_eq!(m.iter().filter(|x| x.is_nan()).count(), 1);
+            assert_eq!(m.iter().filter(|x| x.is_finite()).count(), 19);
+
+            let m = gen.weird_matrix_f64(4, 5, MatrixMode::InfRow);
+            let inf_rows: std::vec::Vec<_> = m
+                .chunks(5)
+                .map(|row| row.iter().all(|x| x.is_infinite()))
+                .collect();
+            assert_eq!(inf_rows.iter().filter(|&&r| r).count(), 1);
+            assert_eq!(m.iter().filter(|x| x.is_finite()).count(), 15);
         }
+        assert!(had_nan_in_weird);
 
-        // every bit should be generated at least once, given enough attempts
-        assert_eq!(coverage, u64::MAX, "{:064b}", coverage);
+        for mode in [MatrixMode::Weird, MatrixMode::SingleNan, MatrixMode::InfRow] {
+            assert!(gen.weird_matrix_f64(0, 5, mode).is_empty());
+            assert!(gen.weird_matrix_f64(5, 0, mode).is_empty());
+        }
     }
 
     #[test]
-    fn special_f32() {
-        let mut gen = Wdg::with_seed(0x69_1b_e9_82_15_ed_a0_7d);
-        for _ in 0..10000 {
-            gen.special_f32();
+    fn subnormal_boundary_f32() {
+        let mut gen = Wdg::with_seed(0xe0_5c_a2_3b_97_14_f6_d8);
+        let smallest = f32::from_bits(1);
+        let largest = float_utils::f32_step_ulps(f32::MIN_POSITIVE, -1);
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            let x = gen.subnormal_boundary_f32();
+            assert!(x.is_subnormal());
+            for (i, boundary) in [smallest, -smallest, largest, -largest]
+                .into_iter()
+                .enumerate()
+            {
+                seen[i] |= f32_exact_eq(x, boundary);
+            }
         }
+        assert!(seen.iter().all(|&s| s));
     }
 
     #[test]
-    fn special_f64() {
-        let mut gen = Wdg::with_seed(0xf5_31_9e_51_c4_1f_9e_35);
-        for _ in 0..10000 {
-            gen.special_f64();
+    fn subnormal_boundary_f64() {
+        let mut gen = Wdg::with_seed(0x7b_c4_18_f9_05_62_ad_e3);
+        let smallest = f64::from_bits(1);
+        let largest = float_utils::f64_step_ulps(f64::MIN_POSITIVE, -1);
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            let x = gen.subnormal_boundary_f64();
+            assert!(x.is_subnormal());
+            for (i, boundary) in [smallest, -smallest, largest, -largest]
+                .into_iter()
+                .enumerate()
+            {
+                seen[i] |= f64_exact_eq(x, boundary);
+            }
         }
+        assert!(seen.iter().all(|&s| s));
     }
 
-    macro_rules! int_uint {
-        ($($t:ty),+ $(,)?) => {
-            $(
-                int_uint_inner!($t);
-            )+
-        };
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn recording() {
+        let mut gen = Wdg::with_seed(0x5c_a8_e2_17_f9_40_b3_6d).with_recording();
+        let mut expected = Wdg::with_seed(0x5c_a8_e2_17_f9_40_b3_6d);
+        let x: f32 = gen.weird();
+        let y = gen.record(|g| g.i8());
+        assert!(f32_exact_eq(x, expected.f32()));
+        assert_eq!(y, expected.i8());
+
+        assert_eq!(
+            gen.log(),
+            [
+                ReplayEntry {
+                    type_name: "f32",
+                    bits: x.to_bits() as u128
+                },
+                ReplayEntry {
+                    type_name: "i8",
+                    bits: y as u8 as u128
+                },
+            ]
+        );
+        assert_eq!(gen.take_log().len(), 2);
+        assert!(gen.log().is_empty());
+        assert_eq!(gen.into_inner().get_seed(), expected.get_seed());
     }
 
-    macro_rules! int_uint_inner {
-        ($t:ty) => {
-            paste! {
-                #[test]
-                pub fn [<special_ $t>]() {
-                    let mut gen = Wdg::with_seed(0x29_2d_3a_df_ed_dd_c0_82);
-                    for _ in 0..10000 {
-                        gen.[<special_ $t>]();
-                    }
-                }
+    #[test]
+    fn weird_map() {
+        #[derive(Debug, PartialEq)]
+        struct Wrapper<T>(T);
 
-                #[test]
-                pub fn $t(){
-                    let mut gen = Wdg::with_seed(0x8e_bd_46_37_50_b4_9b_1a);
-                    for _ in 0..10000 {
-                        gen.$t();
-                    }
-                }
-            }
-        };
+        let mut gen = Wdg::with_seed(0x27_9c_e4_0b_d5_68_a1_f3);
+        let mut expected = gen.clone();
+        for _ in 0..100 {
+            let Wrapper(x) = gen.weird_map_f32(Wrapper);
+            assert!(f32_exact_eq(x, expected.f32()));
+            assert_eq!(gen.weird_map_u8(Wrapper), Wrapper(expected.u8()));
+            assert_eq!(
+                gen.weird_map_char(|c| c.len_utf8()),
+                expected.char().len_utf8()
+            );
+            assert_eq!(gen.weird_with(Wdg::i64), expected.i64());
+        }
     }
 
-    int_uint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-
     #[test]
-    fn special_f32_range() {
-        let mut gen = Wdg::with_seed(0x90_ae_72_03_34_a0_d7_4b);
-        let mut had_infinite = false;
-        let mut had_neg_infinite = false;
-        let mut had_zero = false;
-        let mut had_neg_zero = false;
-        let mut had_one = false;
-        let mut had_neg_one = false;
-        let mut had_min_positive = false;
-        let mut had_max_negative = false;
-        let mut had_epsilon = false;
-        let mut had_neg_epsilon = false;
-        for _ in 0..10000 {
-            let num = gen.special_f32();
-            had_infinite |= f32_exact_eq(num, f32::INFINITY);
-            had_neg_infinite |= f32_exact_eq(num, f32::NEG_INFINITY);
-            had_zero |= f32_exact_eq(num, 0.0);
-            had_neg_zero |= f32_exact_eq(num, -0.0);
-            had_one |= f32_exact_eq(num, 1.0);
-            had_neg_one |= f32_exact_eq(num, -1.0);
-            had_min_positive |= f32_exact_eq(num, f32::MIN_POSITIVE);
-            had_max_negative |= f32_exact_eq(num, -f32::MIN_POSITIVE);
-            had_epsilon |= f32_exact_eq(num, f32::EPSILON);
-            had_neg_epsilon |= f32_exact_eq(num, -f32::EPSILON);
+    fn weird_tuples() {
+        let mut gen = Wdg::with_seed(0xd7_31_6a_fe_08_c5_92_4b);
+        let mut expected = gen.clone();
+        for _ in 0..100 {
+            let (x, y) = gen.weird_pair::<f32, u8>();
+            assert!(f32_exact_eq(x, expected.f32()));
+            assert_eq!(y, expected.u8());
+
+            let (a, b, c) = gen.weird_triple::<i16, char, u64>();
+            assert_eq!((a, b, c), (expected.i16(), expected.char(), expected.u64()));
+
+            let (a, b, c, d) = gen.weird_quad::<u8, u16, u32, u128>();
+            assert_eq!(
+                (a, b, c, d),
+                (
+                    expected.u8(),
+                    expected.u16(),
+                    expected.u32(),
+                    expected.u128()
+                )
+            );
         }
-        assert!(
-            had_infinite
-                && had_neg_infinite
-                && had_zero
-                && had_neg_zero
-                && had_one
-                && had_neg_one
-                && had_min_positive
-                && had_max_negative
-                && had_epsilon
-                && had_neg_epsilon
-        );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn special_f64_range() {
-        let mut gen = Wdg::with_seed(0x10_6c_a1_34_a5_6d_03_97);
-        let mut had_infinite = false;
-        let mut had_neg_infinite = false;
-        let mut had_zero = false;
-        let mut had_neg_zero = false;
-        let mut had_one = false;
-        let mut had_neg_one = false;
-        let mut had_min_positive = false;
-        let mut had_max_negative = false;
-        let mut had_epsilon = false;
-        let mut had_neg_epsilon = false;
-        for _ in 0..10000 {
-            let num = gen.special_f64();
-            had_infinite |= f64_exact_eq(num, f64::INFINITY);
-            had_neg_infinite |= f64_exact_eq(num, f64::NEG_INFINITY);
-            had_zero |= f64_exact_eq(num, 0.0);
-            had_neg_zero |= f64_exact_eq(num, -0.0);
-            had_one |= f64_exact_eq(num, 1.0);
-            had_neg_one |= f64_exact_eq(num, -1.0);
-            had_min_positive |= f64_exact_eq(num, f64::MIN_POSITIVE);
-            had_max_negative |= f64_exact_eq(num, -f64::MIN_POSITIVE);
-            had_epsilon |= f64_exact_eq(num, f64::EPSILON);
-            had_neg_epsilon |= f64_exact_eq(num, -f64::EPSILON);
+    fn weird_utf16() {
+        let mut gen = Wdg::with_seed(0x84_2f_c9_5b_e0_16_7d_a3);
+        let mut had_invalid = false;
+        let mut had_valid_pair = false;
+        let mut had_bom = false;
+        for _ in 0..1000 {
+            let units = gen.weird_utf16(16);
+            assert!(units.len() <= 16);
+            had_invalid |= char::decode_utf16(units.iter().copied()).any(|c| c.is_err());
+            had_valid_pair |= char::decode_utf16(units.iter().copied())
+                .any(|c| c.is_ok_and(|c| c as u32 >= 0x10000));
+            had_bom |= units.contains(&0xFFFE);
         }
-        assert!(
-            had_infinite
-                && had_neg_infinite
-                && had_zero
-                && had_neg_zero
-                && had_one
-                && had_neg_one
-                && had_min_positive
-                && had_max_negative
-                && had_epsilon
-                && had_neg_epsilon
-        );
+        assert!(had_invalid && had_valid_pair && had_bom);
+        assert!(gen.weird_utf16(0).is_empty());
     }
 
     #[test]
-    fn f32_range() {
-        let mut gen = Wdg::with_seed(0x7c_65_54_c7_d6_a9_d4_b7);
+    fn nan_with_payload() {
+        let mut gen = Wdg::with_seed(0x0f_a4_b7_5e_c2_91_38_d6);
+        let mut had_quiet = false;
+        let mut had_signaling = false;
+        for _ in 0..1000 {
+            let x = gen.nan_f64_with_payload(0xdead_beef);
+            assert!(x.is_nan());
+            assert_eq!(x.to_bits() & ((1 << 51) - 1), 0xdead_beef);
+            had_quiet |= !f64_is_signaling_nan(x);
+            had_signaling |= f64_is_signaling_nan(x);
 
-        // these should all be true by the end, given enough attempts
-        let mut had_normal = false;
-        let mut had_subnormal = false;
-        let mut had_nan = false;
-        let mut had_special = false;
-        for _ in 0..10000 {
-            let num = gen.f32();
-            had_normal |= num.is_normal();
-            had_subnormal |= num.is_subnormal();
-            had_nan |= num.is_nan();
-            had_special |= num.is_infinite()
-                | f32_exact_eq(num, 0.0)
-                | f32_exact_eq(num, -0.0)
-                | f32_exact_eq(num, 1.0)
-                | f32_exact_eq(num, -1.0)
-                | f32_exact_eq(num, f32::MIN)
-                | f32_exact_eq(num, f32::MAX)
-                | f32_exact_eq(num, f32::MIN_POSITIVE)
-                | f32_exact_eq(num, -f32::MIN_POSITIVE)
-                | f32_exact_eq(num, f32::EPSILON)
-                | f32_exact_eq(num, -f32::EPSILON);
+            let x = gen.nan_f64_with_payload(0);
+            assert!(x.is_nan());
+            assert_eq!(x.to_bits() & ((1 << 51) - 1), 1);
+
+            let x = gen.nan_f64_with_payload(u64::MAX);
+            assert_eq!(x.to_bits() & ((1 << 51) - 1), (1 << 51) - 1);
+
+            let x = gen.nan_f32_with_payload(1 << 22);
+            assert!(x.is_nan());
+            assert_eq!(x.to_bits() & ((1 << 22) - 1), 1);
         }
-        assert!(had_normal && had_subnormal && had_nan && had_special);
+        assert!(had_quiet && had_signaling);
     }
 
     #[test]
-    fn f64_range() {
-        let mut gen = Wdg::with_seed(0x9a_a4_ee_0f_08_ba_d9_de);
-
-        // these should all be true by the end, given enough attempts
-        let mut had_normal = false;
-        let mut had_subnormal = false;
-        let mut had_nan = false;
-        let mut had_special = false;
+    fn exotic() {
+        let mut gen = Wdg::with_seed(0x6b_d0_2e_f8_47_a3_1c_95);
+        let mut had_neg_zero = false;
+        let mut had_neg_nan = false;
+        let mut had_signaling = false;
         for _ in 0..10000 {
-            let num = gen.f64();
-            had_normal |= num.is_normal();
-            had_subnormal |= num.is_subnormal();
-            had_nan |= num.is_nan();
-            had_special |= num.is_infinite()
-                | f64_exact_eq(num, 0.0)
-                | f64_exact_eq(num, -0.0)
-                | f64_exact_eq(num, 1.0)
-                | f64_exact_eq(num, -1.0)
-                | f64_exact_eq(num, f64::MIN)
-                | f64_exact_eq(num, f64::MAX)
-                | f64_exact_eq(num, f64::MIN_POSITIVE)
-                | f64_exact_eq(num, -f64::MIN_POSITIVE)
-                | f64_exact_eq(num, f64::EPSILON)
-                | f64_exact_eq(num, -f64::EPSILON);
+            let x = gen.exotic_f32();
+            assert!(x.is_nan() || f32_exact_eq(x, -0.0), "{x}");
+            assert!(!f32_exact_eq(x, f32::NAN));
+
+            let x = gen.exotic_f64();
+            assert!(x.is_nan() || f64_exact_eq(x, -0.0), "{x}");
+            assert!(!f64_exact_eq(x, f64::NAN));
+            had_neg_zero |= f64_exact_eq(x, -0.0);
+            had_neg_nan |= x.is_nan() && x.is_sign_negative();
+            had_signaling |= f64_is_signaling_nan(x);
         }
-        assert!(had_normal && had_subnormal && had_nan && had_special);
+        assert!(had_neg_zero && had_neg_nan && had_signaling);
     }
 }
@@ -0,0 +1,70 @@
+//! Generators of weird matrices.
+
+use alloc::vec::Vec;
+
+use crate::Wdg;
+
+/// The structure of the matrices generated by [Wdg::weird_matrix_f64].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatrixMode {
+    /// Every element is generated with [Wdg::f64], so `NAN` and infinite
+    /// values can be anywhere
+    Weird,
+    /// Exactly one element is `NAN`, at a random position, and every other
+    /// element is finite
+    SingleNan,
+    /// Exactly one row is made of infinities of random signs, and every
+    /// other element is finite
+    InfRow,
+}
+
+impl Wdg {
+    /// Generate a matrix of `rows * cols` weird f64 values, as a flat buffer
+    /// in row-major order.
+    ///
+    /// The `mode` selects the structure of the matrix, see [MatrixMode].
+    /// Forcing a single `NAN` or a single row of infinities is useful for
+    /// isolating how they propagate through matrix operations. The finite
+    /// elements are generated with [Wdg::weird_f64_in], over all finite
+    /// values.
+    ///
+    /// ```
+    /// # use weird_data::{MatrixMode, Wdg};
+    /// let mut gen = Wdg::with_seed(0);
+    /// let m = gen.weird_matrix_f64(3, 4, MatrixMode::SingleNan);
+    /// assert_eq!(m.len(), 12);
+    /// assert_eq!(m.iter().filter(|x| x.is_nan()).count(), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows * cols` overflows.
+    pub fn weird_matrix_f64(&mut self, rows: usize, cols: usize, mode: MatrixMode) -> Vec<f64> {
+        let len = rows.checked_mul(cols).expect("matrix size overflows usize");
+        if mode == MatrixMode::Weird {
+            return (0..len).map(|_| self.f64()).collect();
+        }
+
+        let mut matrix: Vec<f64> = (0..len)
+            .map(|_| self.weird_f64_in(f64::MIN, f64::MAX))
+            .collect();
+        if len == 0 {
+            return matrix;
+        }
+        match mode {
+            MatrixMode::SingleNan => matrix[self.0.usize(0..len)] = self.nan_f64(),
+            MatrixMode::InfRow => {
+                let row = self.0.usize(0..rows);
+                for x in &mut matrix[row * cols..(row + 1) * cols] {
+                    *x = if self.0.bool() {
+                        f64::INFINITY
+                    } else {
+                        f64::NEG_INFINITY
+                    };
+                }
+            }
+            MatrixMode::Weird => unreachable!(),
+        }
+        matrix
+    }
+}
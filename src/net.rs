@@ -0,0 +1,90 @@
+//! Generators of weird network addresses.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Generate a random `Ipv4Addr`, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// The special values are the unspecified address `0.0.0.0`, the
+    /// broadcast address `255.255.255.255`, loopback addresses, link-local
+    /// addresses, private addresses and multicast addresses.
+    pub fn ipv4_addr(&mut self) -> Ipv4Addr {
+        match self.0.u8(0..8) {
+            0 => Ipv4Addr::UNSPECIFIED,
+            1 => Ipv4Addr::BROADCAST,
+            2 => Ipv4Addr::LOCALHOST,
+            3 => Ipv4Addr::new(127, self.0.u8(..), self.0.u8(..), self.0.u8(..)),
+            4 => Ipv4Addr::new(169, 254, self.0.u8(..), self.0.u8(..)),
+            5 => Ipv4Addr::new(10, self.0.u8(..), self.0.u8(..), self.0.u8(..)),
+            6 => Ipv4Addr::new(
+                self.0.u8(224..=239),
+                self.0.u8(..),
+                self.0.u8(..),
+                self.0.u8(..),
+            ),
+            7 => Ipv4Addr::from(self.0.u32(..)),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `Ipv6Addr`, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// The special values are the unspecified address `::`, the loopback
+    /// address `::1`, link-local addresses, multicast addresses, the address
+    /// with all bits set, and IPv4-mapped (`::ffff:a.b.c.d`) and
+    /// IPv4-compatible (`::a.b.c.d`) addresses of weird IPv4 addresses.
+    pub fn ipv6_addr(&mut self) -> Ipv6Addr {
+        match self.0.u8(0..8) {
+            0 => Ipv6Addr::UNSPECIFIED,
+            1 => Ipv6Addr::LOCALHOST,
+            2 | 3 => self.ipv4_addr().to_ipv6_mapped(),
+            4 => self.ipv4_addr().to_ipv6_compatible(),
+            5 => Ipv6Addr::from((0xfe80 << 112) | self.0.u128(..(1 << 64))),
+            6 => Ipv6Addr::from((0xff << 120) | self.0.u128(..(1 << 120))),
+            7 => match self.0.bool() {
+                true => Ipv6Addr::from(u128::MAX),
+                false => Ipv6Addr::from(self.0.u128(..)),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `IpAddr`, either V4 or V6, such that special or
+    /// problematic values are much more common than normal.
+    ///
+    /// See [Wdg::ipv4_addr] and [Wdg::ipv6_addr].
+    pub fn ip_addr(&mut self) -> IpAddr {
+        match self.0.bool() {
+            true => IpAddr::V4(self.ipv4_addr()),
+            false => IpAddr::V6(self.ipv6_addr()),
+        }
+    }
+
+    /// Generate a random port, such that special or problematic values are
+    /// much more common than normal.
+    ///
+    /// The special values are 0, 1, 80, 443 and 65535.
+    pub fn port(&mut self) -> u16 {
+        match self.0.u8(0..6) {
+            0 => 0,
+            1 => 1,
+            2 => 80,
+            3 => 443,
+            4 => u16::MAX,
+            5 => self.0.u16(..),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `SocketAddr`, such that special or problematic
+    /// values are much more common than normal.
+    ///
+    /// See [Wdg::ip_addr] and [Wdg::port].
+    pub fn socket_addr(&mut self) -> SocketAddr {
+        SocketAddr::new(self.ip_addr(), self.port())
+    }
+}
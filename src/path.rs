@@ -0,0 +1,124 @@
+//! Generators of weird filesystem paths.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use crate::Wdg;
+
+/// Longest file name allowed by most filesystems.
+const NAME_MAX: usize = 255;
+
+impl Wdg {
+    /// Generate a random `PathBuf` made of problematic components.
+    ///
+    /// The components include empty segments (repeated separators), `.` and
+    /// `..`, names right around `NAME_MAX` (255 bytes), names with leading,
+    /// trailing or embedded spaces and newlines, and names that are not valid
+    /// UTF-8. The path may be absolute or relative, and may end with a
+    /// trailing separator.
+    ///
+    /// On Unix, the non UTF-8 names are made of arbitrary bytes, so
+    /// `to_str()` returns `None` for them. On Windows, they contain unpaired
+    /// surrogates, and the paths may also have drive letters, UNC and verbatim
+    /// prefixes, forward and backward slashes, and reserved names like `CON`.
+    pub fn weird_path(&mut self) -> PathBuf {
+        let mut path = OsString::new();
+        path.push(self.path_prefix());
+
+        let components = self.0.usize(0..=5);
+        for i in 0..components {
+            if i > 0 {
+                path.push(self.path_separator());
+            }
+            path.push(self.path_component());
+        }
+
+        if self.0.u8(0..4) == 0 {
+            path.push(self.path_separator());
+        }
+        PathBuf::from(path)
+    }
+
+    fn path_component(&mut self) -> OsString {
+        match self.0.u8(0..11) {
+            0 => OsString::new(),
+            1 => OsString::from("."),
+            2 => OsString::from(".."),
+            3 => OsString::from("a".repeat(NAME_MAX + self.0.usize(0..=2) - 1)),
+            4 => OsString::from(" "),
+            5 => OsString::from("name with spaces "),
+            6 => OsString::from("line\nbreak"),
+            7 => OsString::from("..."),
+            8 => OsString::from(".hidden"),
+            9 => self.non_utf8_component(),
+            10 => OsString::from(self.weird_string(16).replace(['/', '\\', '\0'], "_")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(unix)]
+    fn path_prefix(&mut self) -> &'static str {
+        ["", "/", "//", "./", "../", "~/"][self.0.usize(0..6)]
+    }
+
+    #[cfg(windows)]
+    fn path_prefix(&mut self) -> &'static str {
+        [
+            "",
+            "\\",
+            "C:",
+            "C:\\",
+            "z:/",
+            "\\\\?\\C:\\",
+            "\\\\server\\share\\",
+            "\\\\.\\",
+            "CON\\",
+        ][self.0.usize(0..9)]
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn path_prefix(&mut self) -> &'static str {
+        ["", "/", "./", "../"][self.0.usize(0..4)]
+    }
+
+    #[cfg(windows)]
+    fn path_separator(&mut self) -> &'static str {
+        ["\\", "/", "\\\\"][self.0.usize(0..3)]
+    }
+
+    #[cfg(not(windows))]
+    fn path_separator(&mut self) -> &'static str {
+        ["/", "//"][self.0.usize(0..2)]
+    }
+
+    /// A path component that is not valid UTF-8
+    #[cfg(unix)]
+    fn non_utf8_component(&mut self) -> OsString {
+        use std::os::unix::ffi::OsStringExt;
+
+        // a leading continuation byte is never valid UTF-8
+        let mut bytes = vec![self.0.u8(0x80..=0xBF)];
+        for _ in 0..self.0.usize(0..8) {
+            bytes.push(match self.0.bool() {
+                true => self.0.alphanumeric() as u8,
+                false => self.0.u8(0x80..=0xFF),
+            });
+        }
+        OsString::from_vec(bytes)
+    }
+
+    /// A path component that is not valid UTF-16
+    #[cfg(windows)]
+    fn non_utf8_component(&mut self) -> OsString {
+        use std::os::windows::ffi::OsStringExt;
+
+        // an unpaired surrogate, surrounded by ASCII letters
+        let surrogate = self.0.u16(0xD800..=0xDFFF);
+        OsString::from_wide(&[b'a' as u16, surrogate, b'z' as u16])
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn non_utf8_component(&mut self) -> OsString {
+        OsString::from("\u{FFFD}")
+    }
+}
@@ -0,0 +1,43 @@
+//! Integration with [quickcheck].
+//!
+//! Every wrapper holds a weird value, generated with a [Wdg] seeded from the
+//! quickcheck [Gen]. Shrinking is delegated to the wrapped type.
+
+use paste::paste;
+use quickcheck::{Arbitrary, Gen};
+
+use crate::Wdg;
+
+macro_rules! wrapper {
+    ($($t:ident),+ $(,)?) => {
+        $(
+            paste! {
+                #[doc = concat!("A weird `", stringify!($t), "` for quickcheck properties, see [Wdg::", stringify!($t), "].")]
+                ///
+                /// ```
+                #[doc = concat!("use weird_data::Weird", stringify!([<$t:camel>]), ";")]
+                ///
+                #[doc = concat!("fn prop(x: Weird", stringify!([<$t:camel>]), ") -> bool {")]
+                ///     x.0 == x.0 || x.0 != x.0
+                /// }
+                ///
+                #[doc = concat!("quickcheck::quickcheck(prop as fn(Weird", stringify!([<$t:camel>]), ") -> bool);")]
+                /// ```
+                #[derive(Clone, Copy, Debug, PartialEq)]
+                pub struct [<Weird $t:camel>](pub $t);
+
+                impl Arbitrary for [<Weird $t:camel>] {
+                    fn arbitrary(g: &mut Gen) -> Self {
+                        Self(Wdg::with_seed(u64::arbitrary(g)).$t())
+                    }
+
+                    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                        Box::new(self.0.shrink().map(Self))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+wrapper!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,46 @@
+//! Integration with the [rand_core] traits.
+//!
+//! Note that these implementations expose the *raw* entropy of the underlying
+//! generator, NOT the weird-biased values produced by the methods of [Wdg].
+//! This makes it possible to plug a [Wdg] into any API accepting an
+//! [RngCore], while sharing the same deterministic stream.
+
+use rand_core::{Error, RngCore, SeedableRng};
+
+use crate::Wdg;
+
+impl RngCore for Wdg {
+    /// Generate a uniformly distributed `u32`, this is NOT weird-biased.
+    fn next_u32(&mut self) -> u32 {
+        self.0.u32(..)
+    }
+
+    /// Generate a uniformly distributed `u64`, this is NOT weird-biased.
+    fn next_u64(&mut self) -> u64 {
+        self.0.u64(..)
+    }
+
+    /// Fill the buffer with uniformly distributed bytes, these are NOT
+    /// weird-biased.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Wdg {
+    /// The seed is interpreted as a little-endian `u64`.
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Wdg::with_seed(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Wdg::with_seed(state)
+    }
+}
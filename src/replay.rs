@@ -0,0 +1,121 @@
+//! Recording the values produced by a generator.
+
+use alloc::vec::Vec;
+
+use crate::{Wdg, Weird};
+
+/// A value recorded by a [Recording], as its type and raw bits.
+///
+/// The bits are the ones of the value zero-extended to 128 bits, for
+/// instance `f32::to_bits` for an `f32`, or the scalar value of a `char`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReplayEntry {
+    /// Name of the type of the value, like `"f32"`
+    pub type_name: &'static str,
+    /// Raw bits of the value
+    pub bits: u128,
+}
+
+/// A value that can be recorded by a [Recording].
+pub trait Recordable {
+    /// Convert the value to an entry of the log.
+    fn to_entry(&self) -> ReplayEntry;
+}
+
+macro_rules! recordable {
+    ($($t:ident => |$x:ident| $bits:expr),+ $(,)?) => {
+        $(
+            impl Recordable for $t {
+                fn to_entry(&self) -> ReplayEntry {
+                    let $x = *self;
+                    ReplayEntry {
+                        type_name: stringify!($t),
+                        bits: $bits,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+recordable!(
+    f32 => |x| x.to_bits() as u128,
+    f64 => |x| x.to_bits() as u128,
+    char => |x| x as u128,
+    bool => |x| x as u128,
+    u8 => |x| x as u128,
+    u16 => |x| x as u128,
+    u32 => |x| x as u128,
+    u64 => |x| x as u128,
+    u128 => |x| x,
+    usize => |x| x as u128,
+    i8 => |x| x as u8 as u128,
+    i16 => |x| x as u16 as u128,
+    i32 => |x| x as u32 as u128,
+    i64 => |x| x as u64 as u128,
+    i128 => |x| x as u128,
+    isize => |x| x as usize as u128,
+);
+
+/// A [Wdg] that records every value it produces, created with
+/// [Wdg::with_recording].
+///
+/// The log stores the exact values, so it can be dumped to debug a failure
+/// even if the mapping from seeds to values changes across versions of this
+/// crate. A plain [Wdg] does not record anything, and has no overhead.
+///
+/// ```
+/// use weird_data::Wdg;
+///
+/// let mut gen = Wdg::with_seed(0).with_recording();
+/// let x: f32 = gen.weird();
+/// let y = gen.record(|g| g.near_boundary_u8());
+/// assert_eq!(gen.take_log().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Recording {
+    wdg: Wdg,
+    log: Vec<ReplayEntry>,
+}
+
+impl Wdg {
+    /// Start recording every value produced by this generator.
+    ///
+    /// See [Recording].
+    #[must_use]
+    pub fn with_recording(self) -> Recording {
+        Recording {
+            wdg: self,
+            log: Vec::new(),
+        }
+    }
+}
+
+impl Recording {
+    /// Generate a value with `f` and record it.
+    pub fn record<T: Recordable>(&mut self, f: impl FnOnce(&mut Wdg) -> T) -> T {
+        let x = f(&mut self.wdg);
+        self.log.push(x.to_entry());
+        x
+    }
+
+    /// Generate a weird value with [Weird::weird] and record it.
+    pub fn weird<T: Weird + Recordable>(&mut self) -> T {
+        self.record(T::weird)
+    }
+
+    /// The values recorded so far.
+    pub fn log(&self) -> &[ReplayEntry] {
+        &self.log
+    }
+
+    /// Take the values recorded so far, leaving the log empty.
+    pub fn take_log(&mut self) -> Vec<ReplayEntry> {
+        core::mem::take(&mut self.log)
+    }
+
+    /// Stop recording, giving back the generator.
+    pub fn into_inner(self) -> Wdg {
+        self.wdg
+    }
+}
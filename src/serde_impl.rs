@@ -0,0 +1,24 @@
+//! Integration with [serde].
+//!
+//! Only the state of the generator is serialized, as a single `u64`. This is
+//! the same value returned by [Wdg::get_seed]. The configuration is not
+//! serialized: a deserialized generator uses the default float weights, so it
+//! only resumes exactly the same stream of values if the original generator
+//! used them too. The float weights set with [WdgBuilder](crate::WdgBuilder)
+//! must be re-applied after deserializing.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Wdg;
+
+impl Serialize for Wdg {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.get_seed())
+    }
+}
+
+impl<'de> Deserialize<'de> for Wdg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Wdg::with_seed)
+    }
+}
@@ -0,0 +1,63 @@
+//! Generators working on slices.
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Pick a random index into a container of length `len`, such that the
+    /// first and the last index are much more common than normal.
+    ///
+    /// Returns `None` if `len` is 0.
+    pub fn choose_index(&mut self, len: usize) -> Option<usize> {
+        match len {
+            0 => None,
+            1 => Some(0),
+            _ => Some(match self.0.u8(0..3) {
+                0 => 0,
+                1 => len - 1,
+                2 => self.0.usize(0..len),
+                _ => unreachable!(),
+            }),
+        }
+    }
+
+    /// Pick a random item of a slice, such that the first and the last item
+    /// are much more common than normal.
+    ///
+    /// Returns `None` if the slice is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        self.choose_index(items.len()).map(|i| &items[i])
+    }
+
+    /// Shuffle a slice uniformly, with the Fisher-Yates algorithm.
+    ///
+    /// Unlike the other generators, this is not biased in any way.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        self.0.shuffle(slice);
+    }
+
+    /// Shuffle a slice, such that orderings that break naive sorting
+    /// algorithms are much more common than normal.
+    ///
+    /// First a mode is picked with equal probability, then it is applied:
+    /// - sorted
+    /// - reverse sorted
+    /// - nearly sorted, i.e. sorted, then a few adjacent pairs are swapped
+    /// - uniformly shuffled, like [Wdg::shuffle]
+    pub fn shuffle_adversarial<T: Ord>(&mut self, slice: &mut [T]) {
+        match self.0.u8(0..4) {
+            0 => slice.sort_unstable(),
+            1 => slice.sort_unstable_by(|a, b| b.cmp(a)),
+            2 => {
+                slice.sort_unstable();
+                if slice.len() >= 2 {
+                    for _ in 0..self.0.usize(1..=3) {
+                        let i = self.0.usize(0..slice.len() - 1);
+                        slice.swap(i, i + 1);
+                    }
+                }
+            }
+            3 => self.shuffle(slice),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Integration with [proptest].
+//!
+//! The strategies in this module generate their initial values with a [Wdg],
+//! seeded from the RNG of the proptest runner, so rare edge cases are
+//! explored before shrinking kicks in. Shrinking uses the default numeric
+//! shrinkers of proptest.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use weird_data::strategies::weird_f64_strategy;
+//!
+//! proptest! {
+//!     fn abs_is_not_negative(x in weird_f64_strategy()) {
+//!         prop_assert!(x.is_nan() || x.abs() >= 0.0);
+//!     }
+//! }
+//!
+//! abs_is_not_negative();
+//! ```
+
+use core::marker::PhantomData;
+
+use paste::paste;
+use proptest::prelude::*;
+use proptest::strategy::{NewTree, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use crate::Wdg;
+
+/// A proptest [Strategy] generating weird values of type `T`.
+#[derive(Debug, Clone, Copy)]
+pub struct WeirdStrategy<T>(PhantomData<fn() -> T>);
+
+macro_rules! strategy {
+    ($($t:ident),+ $(,)?) => {
+        $(
+            paste! {
+                impl Strategy for WeirdStrategy<$t> {
+                    type Tree = proptest::num::$t::BinarySearch;
+                    type Value = $t;
+
+                    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                        let seed = any::<u64>().new_tree(runner)?.current();
+                        let value = Wdg::with_seed(seed).$t();
+                        Ok(proptest::num::$t::BinarySearch::new(value))
+                    }
+                }
+
+                #[doc = concat!("A strategy generating weird `", stringify!($t), "` values, see [Wdg::", stringify!($t), "].")]
+                pub fn [<weird_ $t _strategy>]() -> WeirdStrategy<$t> {
+                    WeirdStrategy(PhantomData)
+                }
+            }
+        )+
+    };
+}
+
+strategy!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
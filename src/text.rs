@@ -0,0 +1,551 @@
+//! Generators of weird strings.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Wdg;
+
+/// Single characters that are legal but tend to confuse text processing.
+const NASTY_CHARS: [char; 14] = [
+    '\0',
+    '\n',
+    '\r',
+    '\u{0B}',    // vertical tab
+    '\u{0C}',    // form feed
+    '\u{85}',    // next line
+    '\u{2028}',  // line separator
+    '\u{2029}',  // paragraph separator
+    '\u{0301}',  // combining acute accent
+    '\u{200B}',  // zero width space
+    '\u{200D}',  // zero width joiner
+    '\u{200F}',  // right-to-left mark
+    '\u{202E}',  // right-to-left override
+    '\u{1F600}', // grinning face, outside of the BMP
+];
+
+/// Grapheme clusters made of multiple code points.
+const CLUSTERS: [&str; 6] = [
+    "e\u{0301}",                                   // e + combining acute accent
+    "\u{0915}\u{094D}\u{0937}",                    // devanagari conjunct
+    "\u{1F1EE}\u{1F1F9}",                          // flag, regional indicator pair
+    "\u{1F44B}\u{1F3FD}",                          // waving hand + skin tone
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", // family, ZWJ sequence
+    "\r\n",
+];
+
+/// Emoji that are commonly joined with ZWJ into a single grapheme.
+const ZWJ_EMOJI: [char; 8] = [
+    '\u{1F468}', // man
+    '\u{1F469}', // woman
+    '\u{1F467}', // girl
+    '\u{1F466}', // boy
+    '\u{2764}',  // heavy black heart
+    '\u{1F48B}', // kiss mark
+    '\u{1F52C}', // microscope
+    '\u{1F680}', // rocket
+];
+
+/// A chunk of 64 chars, repeated to make a long string.
+macro_rules! chunk64 {
+    () => {
+        "0123456789abcdef\u{e9}\u{1F600}\u{200B} \t\u{0301}\u{4E2D}\u{10FFFF}\u{FEFF}ABCDEFGHIJKLMNOPQRSTUVWXYZ!?ghijklmnopq"
+    };
+}
+
+/// A chunk of 256 chars.
+macro_rules! chunk256 {
+    () => {
+        concat!(chunk64!(), chunk64!(), chunk64!(), chunk64!())
+    };
+}
+
+/// Strings for the borrowed variant of [Wdg::weird_cow_str].
+const BORROWED: [&str; 9] = [
+    "",
+    " ",
+    " \t\r\n\u{A0}\u{3000}", // whitespace only
+    "\0",
+    "\u{FEFF}",                                    // BOM
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", // family, ZWJ sequence
+    "\u{202E}olleh",                               // right-to-left override
+    "e\u{0301}\u{0301}\u{0301}",                   // stacked combining marks
+    // 1024 chars
+    concat!(chunk256!(), chunk256!(), chunk256!(), chunk256!()),
+];
+
+impl Wdg {
+    /// Generate a random `String` packed with legal but problematic content.
+    ///
+    /// The string contains up to `max_len` chars, mixing weird chars from
+    /// [Wdg::char], combining diacritics, zero-width joiners, right-to-left
+    /// marks, emoji outside of the BMP, `'\0'`, newline variants, and
+    /// grapheme clusters made of multiple code points. The length is
+    /// generated with [Wdg::weird_len].
+    ///
+    /// Note that the length is counted in chars, not in bytes.
+    pub fn weird_string(&mut self, max_len: usize) -> String {
+        let len = self.weird_len(max_len);
+
+        let mut string = String::new();
+        let mut chars = 0;
+        while chars < len {
+            match self.0.u8(0..3) {
+                0 => string.push(self.char()),
+                1 => string.push(NASTY_CHARS[self.0.usize(0..NASTY_CHARS.len())]),
+                2 => {
+                    let cluster = CLUSTERS[self.0.usize(0..CLUSTERS.len())];
+                    let cluster_len = cluster.chars().count();
+                    if chars + cluster_len > len {
+                        continue;
+                    }
+                    string.push_str(cluster);
+                    chars += cluster_len;
+                    continue;
+                }
+                _ => unreachable!(),
+            }
+            chars += 1;
+        }
+        string
+    }
+
+    /// Generate a random `String` of weird ASCII chars, which never contains a
+    /// byte `>= 0x80`.
+    ///
+    /// The string contains up to `max_len` chars, mixing printable ASCII
+    /// with control chars, `DEL`, and the chars that tend to confuse
+    /// protocols and text processing, like `'\t'`, `'\r'`, `'\n'`, `'\0'`,
+    /// quotes and backslashes. The length is generated with [Wdg::weird_len].
+    ///
+    /// As every char is ASCII, the string is also valid Latin-1.
+    pub fn weird_ascii_string(&mut self, max_len: usize) -> String {
+        const TRICKY: [char; 12] = [
+            '\t', '\r', '\n', '\0', '\x7F', ' ', '"', '\'', '\\', '%', '\x1B', '\x08',
+        ];
+
+        let len = self.weird_len(max_len);
+        (0..len)
+            .map(|_| match self.0.u8(0..3) {
+                0 => char::from(self.0.u8(0x20..0x7F)),
+                1 => char::from(self.0.u8(0x00..0x20)),
+                2 => TRICKY[self.0.usize(0..TRICKY.len())],
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    /// Generate a random `String` packed with the chars that are tricky to
+    /// escape in JSON.
+    ///
+    /// The string contains up to `max_len` chars, mixing ASCII letters with
+    /// quotes, backslashes, the control chars that must be escaped as
+    /// `\u00XX`, the forward slash, which may optionally be escaped, chars
+    /// outside of the BMP, which are escaped as surrogate pairs, text that
+    /// looks like an escape sequence, and `'\u{2028}'` and `'\u{2029}'`,
+    /// which are valid in JSON but not in JavaScript string literals. The
+    /// length is generated with [Wdg::weird_len].
+    pub fn weird_json_string(&mut self, max_len: usize) -> String {
+        const LOOKALIKES: [&str; 4] = ["\\u0000", "\\\"", "\\n", "\\ud83d"];
+
+        let len = self.weird_len(max_len);
+        let mut string = String::new();
+        let mut chars = 0;
+        while chars < len {
+            match self.0.u8(0..8) {
+                0 => string.push(self.0.alphanumeric()),
+                1 => string.push(['"', '\\'][self.0.usize(0..2)]),
+                2 => string.push(self.control_char()),
+                3 => string.push('/'),
+                4 => string.push(self.char_from(0x10000..=char::MAX as u32)),
+                5 => string.push(['\u{2028}', '\u{2029}'][self.0.usize(0..2)]),
+                6 => {
+                    let lookalike = LOOKALIKES[self.0.usize(0..LOOKALIKES.len())];
+                    if chars + lookalike.len() > len {
+                        continue;
+                    }
+                    string.push_str(lookalike);
+                    chars += lookalike.len();
+                    continue;
+                }
+                7 => string.push(self.char()),
+                _ => unreachable!(),
+            }
+            chars += 1;
+        }
+        string
+    }
+
+    /// Generate a random `Cow<str>`, which is borrowed or owned with equal
+    /// probability.
+    ///
+    /// This exercises the code paths that handle each variant. The borrowed
+    /// strings come from a small table of nasty static strings: the empty
+    /// string, whitespace only strings, `"\0"`, the BOM, emoji sequences,
+    /// right-to-left overrides, stacked combining marks and a string of 1024
+    /// chars, regardless of `max_len`. The owned strings are generated with
+    /// [Wdg::weird_string], with at most `max_len` chars.
+    pub fn weird_cow_str(&mut self, max_len: usize) -> Cow<'static, str> {
+        if self.0.bool() {
+            Cow::Borrowed(BORROWED[self.0.usize(0..BORROWED.len())])
+        } else {
+            Cow::Owned(self.weird_string(max_len))
+        }
+    }
+
+    /// Generate a random byte sequence that is NOT valid UTF-8.
+    ///
+    /// The sequence is at most `max_len` bytes long, and contains at least one
+    /// of the following errors, mixed with valid chars:
+    /// - lone continuation bytes, in `0x80..=0xBF`
+    /// - lead bytes of multi-byte sequences, truncated before their end
+    /// - overlong encodings of ASCII chars
+    /// - encoded surrogate code points, in `0xD800..=0xDFFF`
+    ///
+    /// The length is biased towards 1 and `max_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0, as the empty sequence is valid UTF-8.
+    pub fn invalid_utf8(&mut self, max_len: usize) -> Vec<u8> {
+        assert!(max_len > 0, "an empty byte sequence is always valid UTF-8");
+
+        let len = match self.0.u8(0..3) {
+            0 => 1,
+            1 => max_len,
+            2 => self.0.usize(1..=max_len),
+            _ => unreachable!(),
+        };
+
+        let mut bytes = Vec::with_capacity(len);
+        let mut has_error = false;
+        while bytes.len() < len {
+            let remaining = len - bytes.len();
+
+            // make sure there is always room for at least one error
+            if (has_error || remaining > 4) && self.0.bool() {
+                let mut buf = [0; 4];
+                let encoded = self.char().encode_utf8(&mut buf).as_bytes();
+                if encoded.len() <= remaining - !has_error as usize {
+                    bytes.extend_from_slice(encoded);
+                }
+                continue;
+            }
+
+            match self.0.u8(0..4) {
+                // lone continuation byte
+                0 => bytes.push(self.0.u8(0x80..=0xBF)),
+
+                // truncated multi-byte sequence, followed by an ASCII byte so
+                // that later continuation bytes can't complete it
+                1 => {
+                    let (lead, continuations) = match self.0.u8(0..3) {
+                        0 => (self.0.u8(0xC2..=0xDF), 1),
+                        1 => (self.0.u8(0xE1..=0xEC), 2),
+                        2 => (self.0.u8(0xF1..=0xF3), 3),
+                        _ => unreachable!(),
+                    };
+                    let present = self.0.usize(0..continuations).min(remaining - 1);
+                    bytes.push(lead);
+                    for _ in 0..present {
+                        bytes.push(self.0.u8(0x80..=0xBF));
+                    }
+                    if bytes.len() < len {
+                        bytes.push(self.0.u8(0x00..0x80));
+                    }
+                }
+
+                // overlong encoding of an ASCII char
+                2 if remaining >= 2 => {
+                    let ascii = self.0.u8(0x00..0x80);
+                    bytes.push(0xC0 | (ascii >> 6));
+                    bytes.push(0x80 | (ascii & 0b11_1111));
+                }
+
+                // encoded surrogate
+                3 if remaining >= 3 => {
+                    bytes.push(0xED);
+                    bytes.push(self.0.u8(0xA0..=0xBF));
+                    bytes.push(self.0.u8(0x80..=0xBF));
+                }
+
+                _ => continue,
+            }
+            has_error = true;
+        }
+        bytes
+    }
+
+    /// Generate a random text made of `n` short lines, separated by a mix of
+    /// different line endings.
+    ///
+    /// Every line is made of up to 8 ASCII alphanumeric chars, and every line
+    /// ending is picked independently with [Wdg::newline], so a single text
+    /// mixes `"\n"`, `"\r\n"`, `"\r"` and the other line endings. Half of
+    /// the time, the text also ends with a line ending.
+    pub fn weird_lines(&mut self, n: usize) -> String {
+        let mut text = String::new();
+        for i in 0..n {
+            if i > 0 {
+                text.push_str(self.newline());
+            }
+            for _ in 0..self.0.usize(0..=8) {
+                text.push(self.0.alphanumeric());
+            }
+        }
+        if n > 0 && self.0.bool() {
+            text.push_str(self.newline());
+        }
+        text
+    }
+
+    /// Generate a random UTF-16 sequence, which is often NOT valid.
+    ///
+    /// The sequence is at most `max_len` code units long, and mixes weird
+    /// chars from [Wdg::char], correctly paired surrogates, the BOM and
+    /// byte-swapped BOM code units (`0xFEFF` and `0xFFFE`), and the error
+    /// cases of lenient decoders, which are the most common: unpaired high
+    /// and low surrogates, and pairs in the wrong order. The length is
+    /// biased towards 0, 1 and `max_len`.
+    pub fn weird_utf16(&mut self, max_len: usize) -> Vec<u16> {
+        let len = match self.0.u8(0..4) {
+            0 => 0,
+            1 => max_len.min(1),
+            2 => max_len,
+            3 => self.0.usize(0..=max_len),
+            _ => unreachable!(),
+        };
+
+        let mut units = Vec::with_capacity(len + 1);
+        while units.len() < len {
+            match self.0.u8(0..8) {
+                // unpaired high surrogate, followed by a non-surrogate
+                0 | 1 => {
+                    units.push(self.0.u16(0xD800..=0xDBFF));
+                    units.push(self.0.u16(0x0000..0xD800));
+                }
+                // unpaired low surrogate
+                2 | 3 => units.push(self.0.u16(0xDC00..=0xDFFF)),
+                // surrogate pair in the wrong order
+                4 => {
+                    units.push(self.0.u16(0xDC00..=0xDFFF));
+                    units.push(self.0.u16(0xD800..=0xDBFF));
+                }
+                // correctly paired surrogates
+                5 => {
+                    let c = char::from_u32(self.0.u32(0x10000..=char::MAX as u32)).unwrap();
+                    units.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+                }
+                6 => units.push([0xFEFF, 0xFFFE][self.0.usize(0..2)]),
+                7 => units.extend_from_slice(self.char().encode_utf16(&mut [0; 2])),
+                _ => unreachable!(),
+            }
+        }
+        units.truncate(len);
+        units
+    }
+
+    /// Generate a random grapheme cluster made of many code points.
+    ///
+    /// The clusters are: a base letter with a stack of combining marks,
+    /// often 10 or more, a pair of regional indicators (a flag), an emoji ZWJ
+    /// sequence like a family, possibly with skin tone modifiers, a keycap
+    /// sequence, a tag sequence flag like the one of England, a Hangul
+    /// syllable made of conjoining jamo, and other common clusters like
+    /// `"\r\n"`.
+    pub fn weird_grapheme(&mut self) -> String {
+        let mut grapheme = String::new();
+        match self.0.u8(0..7) {
+            0 => {
+                grapheme.push(self.0.alphabetic());
+                let marks = match self.0.u8(0..3) {
+                    0 => self.0.usize(1..10),
+                    _ => self.0.usize(10..=64),
+                };
+                for _ in 0..marks {
+                    grapheme.push(self.char_from(0x0300..=0x036F));
+                }
+            }
+            1 => {
+                grapheme.push(self.char_from(0x1F1E6..=0x1F1FF));
+                grapheme.push(self.char_from(0x1F1E6..=0x1F1FF));
+            }
+            2 => {
+                for i in 0..self.0.usize(2..=7) {
+                    if i > 0 {
+                        grapheme.push('\u{200D}');
+                    }
+                    grapheme.push(ZWJ_EMOJI[self.0.usize(0..ZWJ_EMOJI.len())]);
+                    if self.0.bool() {
+                        // skin tone modifier
+                        grapheme.push(self.char_from(0x1F3FB..=0x1F3FF));
+                    }
+                }
+            }
+            3 => {
+                grapheme.push(self.0.digit(10));
+                grapheme.push('\u{FE0F}');
+                grapheme.push('\u{20E3}');
+            }
+            4 => {
+                grapheme.push_str("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}")
+            }
+            5 => {
+                grapheme.push(self.char_from(0x1100..=0x1112));
+                grapheme.push(self.char_from(0x1161..=0x1175));
+                grapheme.push(self.char_from(0x11A8..=0x11C2));
+            }
+            6 => grapheme.push_str(CLUSTERS[self.0.usize(0..CLUSTERS.len())]),
+            _ => unreachable!(),
+        }
+        grapheme
+    }
+
+    /// Generate a random `String` made of `n` grapheme clusters from
+    /// [Wdg::weird_grapheme].
+    pub fn weird_graphemes(&mut self, n: usize) -> String {
+        (0..n).map(|_| self.weird_grapheme()).collect()
+    }
+
+    /// Generate a decimal string that lands on or right next to a rounding
+    /// boundary of f32, together with its correctly rounded f32 value.
+    ///
+    /// This is useful for differential testing of float parsers. The string
+    /// is the exact decimal representation of one of the following, with a
+    /// random sign:
+    /// - the midpoint between two adjacent f32 values, which is a tie that
+    ///   rounds to the value with an even mantissa
+    /// - the nearest f64 value right below or right above such a midpoint
+    /// - a midpoint followed by hundreds of zeros and a final `1`, so that
+    ///   only the very last digit breaks the tie
+    ///
+    /// The adjacent values are normal, subnormal, zero, or right around
+    /// `f32::MIN_POSITIVE`, where parsers may underflow. The digit strings of
+    /// small values are very long, up to about 150 digits, even without
+    /// trailing zeros.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let (string, expected) = gen.weird_f32_from_decimal_string();
+    /// assert_eq!(string.parse::<f32>().unwrap().to_bits(), expected.to_bits());
+    /// ```
+    pub fn weird_f32_from_decimal_string(&mut self) -> (String, f32) {
+        use crate::float_utils::{f32_step_ulps, f64_step_ulps};
+
+        // the lower of two adjacent positive f32 values
+        let lo = match self.0.u8(0..4) {
+            0 => self.normal_f32().abs().min(f32_step_ulps(f32::MAX, -1)),
+            1 => self.subnormal_f32().abs(),
+            2 => f32_step_ulps(f32::MIN_POSITIVE, self.0.i32(-2..=1)),
+            3 => 0.0,
+            _ => unreachable!(),
+        };
+        let hi = f32_step_ulps(lo, 1);
+
+        // exact, as f64 has more than one extra bit of mantissa
+        let mid = (lo as f64 + hi as f64) / 2.0;
+
+        let (mut string, expected) = match self.0.u8(0..4) {
+            // `as` rounds ties to even, like a correct parser
+            0 => (exact_decimal(mid), mid as f32),
+            1 => {
+                let below = f64_step_ulps(mid, -1);
+                (exact_decimal(below), below as f32)
+            }
+            2 => {
+                let above = f64_step_ulps(mid, 1);
+                (exact_decimal(above), above as f32)
+            }
+            3 => {
+                let mut string = exact_decimal(mid);
+                if !string.contains('.') {
+                    string.push('.');
+                }
+                for _ in 0..self.0.usize(100..=1000) {
+                    string.push('0');
+                }
+                string.push('1');
+                (string, hi)
+            }
+            _ => unreachable!(),
+        };
+
+        if self.0.bool() {
+            string.insert(0, '-');
+            (string, -expected)
+        } else {
+            (string, expected)
+        }
+    }
+
+    /// Generate a random `String` with multi-byte chars, and a byte index into
+    /// it that is NOT a char boundary.
+    ///
+    /// Slicing a `&str` at the index panics, so this is useful for checking
+    /// that code uses `is_char_boundary` before slicing. The string is made
+    /// of 1 to 16 chars, mixing ASCII with 2, 3 and 4 byte chars, and it
+    /// always contains at least one 4 byte char, like an emoji. Half of the
+    /// time, the index lands in the middle of a 4 byte char.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let (string, index) = gen.weird_utf8_with_split_points();
+    /// assert!(!string.is_char_boundary(index));
+    /// ```
+    pub fn weird_utf8_with_split_points(&mut self) -> (String, usize) {
+        let mut string = String::new();
+        for _ in 0..self.0.usize(1..=16) {
+            let c = match self.0.u8(0..4) {
+                0 => self.0.alphanumeric(),
+                1 => self.char_from(0x80..=0x7FF),
+                2 => self.char_from(0x800..=0xD7FF),
+                3 => self.char_from(0x1F300..=0x1FAFF),
+                _ => unreachable!(),
+            };
+            string.push(c);
+        }
+
+        // make sure there is at least one 4 byte char
+        if !string.chars().any(|c| c.len_utf8() == 4) {
+            let at = self.0.usize(0..=string.chars().count());
+            let c = self.char_from(0x10000..=char::MAX as u32);
+            let byte = string
+                .char_indices()
+                .nth(at)
+                .map_or(string.len(), |(i, _)| i);
+            string.insert(byte, c);
+        }
+
+        let min_len = if self.0.bool() { 4 } else { 2 };
+        let multi_byte: Vec<(usize, char)> = string
+            .char_indices()
+            .filter(|(_, c)| c.len_utf8() >= min_len)
+            .collect();
+        let (start, c) = multi_byte[self.0.usize(0..multi_byte.len())];
+        let index = start + self.0.usize(1..c.len_utf8());
+        (string, index)
+    }
+
+    /// Pick a char from a range of scalar values that contains no surrogates.
+    fn char_from(&mut self, range: core::ops::RangeInclusive<u32>) -> char {
+        char::from_u32(self.0.u32(range)).unwrap()
+    }
+}
+
+/// The exact decimal representation of a positive finite f64, without an
+/// exponent.
+fn exact_decimal(x: f64) -> String {
+    let bits = x.to_bits();
+    let biased = (bits >> 52) as i32;
+    let (mantissa, exponent) = match biased {
+        0 => (bits, -1074),
+        _ => (bits & ((1 << 52) - 1) | (1 << 52), biased - 1075),
+    };
+
+    // a binary fraction with k fractional bits has exactly k decimal digits
+    let lowest_bit = exponent + mantissa.trailing_zeros() as i32;
+    let precision = (-lowest_bit).max(0) as usize;
+    alloc::format!("{x:.precision$}")
+}
@@ -0,0 +1,137 @@
+//! Generators of weird time-related values.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Wdg;
+
+impl Wdg {
+    /// Generate a random `Duration` "special" value
+    ///
+    /// The special values are `Duration::ZERO`, `Duration::MAX`, one
+    /// nanosecond, exactly one second, the largest duration below one second
+    /// and the largest whole number of seconds.
+    pub fn special_duration(&mut self) -> Duration {
+        match self.0.u8(0..6) {
+            0 => Duration::ZERO,
+            1 => Duration::MAX,
+            2 => Duration::from_nanos(1),
+            3 => Duration::from_secs(1),
+            4 => Duration::from_nanos(999_999_999),
+            5 => Duration::from_secs(u64::MAX),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `Duration`, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// Besides the values of [Wdg::special_duration], this often generates
+    /// durations with 999_999_999 nanoseconds, right before a carry into the
+    /// seconds. The seconds are generated with [Wdg::u64], so they are often
+    /// close to `u64::MAX`.
+    pub fn duration(&mut self) -> Duration {
+        match self.0.u8(0..3) {
+            0 => self.special_duration(),
+            1 => Duration::new(self.u64(), 999_999_999),
+            2 => Duration::new(self.u64(), self.0.u32(0..1_000_000_000)),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random pair of `Duration`, for testing interval arithmetic.
+    ///
+    /// The pairs are biased towards the cases where subtraction underflows
+    /// or addition overflows. The distribution is as follows:
+    /// - 37.5% the second is larger than the first, so that
+    ///   `a.checked_sub(b)` is `None`, often by a single nanosecond
+    /// - 12.5% the first is larger than the second
+    /// - 25% both are equal, so that the difference is `Duration::ZERO`
+    /// - 12.5% both are `Duration::MAX`
+    /// - 12.5% two independent values from [Wdg::duration]
+    pub fn weird_duration_pair(&mut self) -> (Duration, Duration) {
+        const NANO: Duration = Duration::from_nanos(1);
+
+        match self.0.u8(0..8) {
+            0..=3 => {
+                let a = self.duration().min(Duration::MAX - NANO);
+                let b = match self.0.u8(0..3) {
+                    0 => a + NANO,
+                    1 => Duration::MAX,
+                    2 => a.saturating_add(self.duration()).max(a + NANO),
+                    _ => unreachable!(),
+                };
+                // one in four is the other way around
+                if self.0.u8(0..4) == 0 {
+                    (b, a)
+                } else {
+                    (a, b)
+                }
+            }
+            4 | 5 => {
+                let a = self.duration();
+                (a, a)
+            }
+            6 => (Duration::MAX, Duration::MAX),
+            7 => (self.duration(), self.duration()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generate a random `SystemTime`, such that special or problematic values
+    /// are much more common than normal.
+    ///
+    /// The values include the UNIX epoch exactly, one nanosecond before and
+    /// after it, the end of the year 9999, the 32-bit overflow points of
+    /// 2038 and 2106, the year 1900, and the earliest and latest times the
+    /// platform can represent. Times far from the epoch are clamped to the
+    /// representable range, so this never panics.
+    pub fn system_time(&mut self) -> SystemTime {
+        match self.0.u8(0..10) {
+            0 => UNIX_EPOCH,
+            1 => epoch_offset(Duration::from_nanos(1), false),
+            2 => epoch_offset(Duration::from_nanos(1), true),
+            // 9999-12-31T23:59:59.999999999Z
+            3 => epoch_offset(Duration::new(253_402_300_799, 999_999_999), false),
+            4 => epoch_offset(
+                Duration::from_secs(i32::MAX as u64 + self.0.u64(0..=1)),
+                false,
+            ),
+            5 => epoch_offset(
+                Duration::from_secs(u32::MAX as u64 + self.0.u64(0..=1)),
+                false,
+            ),
+            // 1900-01-01T00:00:00Z
+            6 => epoch_offset(Duration::from_secs(2_208_988_800), true),
+            7 => epoch_offset(Duration::MAX, self.0.bool()),
+            8 | 9 => epoch_offset(self.duration(), self.0.bool()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Offset the UNIX epoch by `offset`, forwards or backwards, clamping to the
+/// range of times that the platform can represent.
+fn epoch_offset(offset: Duration, before: bool) -> SystemTime {
+    let apply = |d| {
+        if before {
+            UNIX_EPOCH.checked_sub(d)
+        } else {
+            UNIX_EPOCH.checked_add(d)
+        }
+    };
+    if let Some(time) = apply(offset) {
+        return time;
+    }
+
+    // binary search the largest whole number of seconds that fits
+    let (mut lo, mut hi) = (0, offset.as_secs());
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if apply(Duration::from_secs(mid)).is_some() {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    apply(Duration::from_secs(lo)).unwrap()
+}
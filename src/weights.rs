@@ -0,0 +1,208 @@
+//! Configurable category weights for the float generators.
+
+use fastrand as fr;
+
+use crate::Wdg;
+
+/// Relative weights of the categories of floats generated by
+/// [Wdg::f32_weighted](crate::Wdg::f32_weighted) and
+/// [Wdg::f64_weighted](crate::Wdg::f64_weighted).
+///
+/// Each category is picked with a probability proportional to its weight, so
+/// a weight of zero fully excludes that category. The [Default] weights are
+/// all equal, which reproduces the distribution of [Wdg::f32](crate::Wdg::f32)
+/// and [Wdg::f64](crate::Wdg::f64).
+///
+/// ```
+/// use weird_data::{FloatWeights, Wdg};
+///
+/// // 70% NAN, 10% for every other category
+/// let weights = FloatWeights::new(1, 1, 7, 1);
+/// let mut gen = Wdg::with_seed(0);
+/// let num = gen.f32_weighted(&weights);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FloatWeights {
+    /// Weight of normal values
+    pub normal: u32,
+    /// Weight of subnormal values
+    pub subnormal: u32,
+    /// Weight of `NAN` values, including all possible payloads
+    pub nan: u32,
+    /// Weight of "special" values, such as `INFINITY` and `-0.0`
+    pub special: u32,
+}
+
+/// Number of floats in each category, as counted by
+/// [Wdg::sample_f32_categories](crate::Wdg::sample_f32_categories).
+///
+/// The categories are the same as for [FloatWeights]. A value that is both a
+/// "special" value and normal, like `1.0`, is only counted as special.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FloatCategoryCounts {
+    /// Number of normal values
+    pub normal: usize,
+    /// Number of subnormal values
+    pub subnormal: usize,
+    /// Number of `NAN` values
+    pub nan: usize,
+    /// Number of "special" values, such as `INFINITY` and `-0.0`
+    pub special: usize,
+}
+
+impl FloatCategoryCounts {
+    /// Total number of values that were counted.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.normal + self.subnormal + self.nan + self.special
+    }
+}
+
+/// A category of generated floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FloatCategory {
+    Normal,
+    Subnormal,
+    Nan,
+    Special,
+}
+
+impl FloatWeights {
+    #[must_use]
+    pub const fn new(normal: u32, subnormal: u32, nan: u32, special: u32) -> Self {
+        Self {
+            normal,
+            subnormal,
+            nan,
+            special,
+        }
+    }
+
+    /// Pick a category proportionally to the weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all weights are zero.
+    pub(crate) fn pick(&self, rng: &mut fr::Rng) -> FloatCategory {
+        let total =
+            self.normal as u64 + self.subnormal as u64 + self.nan as u64 + self.special as u64;
+        assert!(
+            total > 0,
+            "at least one float category must have a nonzero weight"
+        );
+
+        let mut choice = rng.u64(0..total);
+        for (weight, category) in [
+            (self.normal, FloatCategory::Normal),
+            (self.subnormal, FloatCategory::Subnormal),
+            (self.nan, FloatCategory::Nan),
+        ] {
+            if choice < weight as u64 {
+                return category;
+            }
+            choice -= weight as u64;
+        }
+        FloatCategory::Special
+    }
+}
+
+impl Default for FloatWeights {
+    fn default() -> Self {
+        Self::new(1, 1, 1, 1)
+    }
+}
+
+/// A builder for a [Wdg] with configured float categories, created with
+/// [Wdg::builder].
+///
+/// Excluded categories are never generated by [Wdg::f32] and [Wdg::f64], and
+/// the remaining categories are picked proportionally to their weights, see
+/// [FloatWeights].
+///
+/// ```
+/// use weird_data::Wdg;
+///
+/// // only NAN values
+/// let mut gen = Wdg::builder()
+///     .seed(0)
+///     .exclude_normal()
+///     .exclude_subnormal()
+///     .exclude_special()
+///     .build();
+/// assert!(gen.f64().is_nan());
+/// ```
+///
+/// Note that the weights are not part of the seed, so they are not kept by
+/// [Wdg::get_seed] and [Wdg::with_seed], and by serialization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct WdgBuilder {
+    seed: Option<u64>,
+    weights: FloatWeights,
+}
+
+impl WdgBuilder {
+    /// Set the initial seed of the generator.
+    ///
+    /// Without the `std` feature, this is required.
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the weights of all float categories at once.
+    pub const fn weights(mut self, weights: FloatWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Never generate normal values.
+    pub const fn exclude_normal(mut self) -> Self {
+        self.weights.normal = 0;
+        self
+    }
+
+    /// Never generate subnormal values.
+    pub const fn exclude_subnormal(mut self) -> Self {
+        self.weights.subnormal = 0;
+        self
+    }
+
+    /// Never generate `NAN` values.
+    pub const fn exclude_nan(mut self) -> Self {
+        self.weights.nan = 0;
+        self
+    }
+
+    /// Never generate "special" values, such as `INFINITY` and `-0.0`.
+    pub const fn exclude_special(mut self) -> Self {
+        self.weights.special = 0;
+        self
+    }
+
+    /// Build the configured generator.
+    ///
+    /// If no seed was set, this forks the global generator, like
+    /// [Wdg::new].
+    ///
+    /// # Panics
+    ///
+    /// Panics if all float categories are excluded, or if no seed was set
+    /// without the `std` feature.
+    pub fn build(self) -> Wdg {
+        let w = &self.weights;
+        assert!(
+            w.normal != 0 || w.subnormal != 0 || w.nan != 0 || w.special != 0,
+            "all float categories are excluded, at least one must remain"
+        );
+
+        let rng = match self.seed {
+            Some(seed) => fr::Rng::with_seed(seed),
+            #[cfg(feature = "std")]
+            None => Wdg::new().0,
+            #[cfg(not(feature = "std"))]
+            None => panic!("a seed must be set with `WdgBuilder::seed` without the `std` feature"),
+        };
+        Wdg(rng, self.weights)
+    }
+}
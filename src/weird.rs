@@ -0,0 +1,205 @@
+//! The [Weird] trait, for generating weird values generically.
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use paste::paste;
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+
+use crate::Wdg;
+
+/// A type that can be generated by a [Wdg], such that special or problematic
+/// values are much more common than normal.
+///
+/// This is useful for writing generic tests, for instance:
+///
+/// ```
+/// use weird_data::{Wdg, Weird};
+///
+/// fn roundtrip<T: Weird + PartialEq + Copy>(gen: &mut Wdg) {
+///     let x: T = Weird::weird(gen);
+///     assert!(x == x.clone());
+/// }
+///
+/// let mut gen = Wdg::with_seed(0);
+/// roundtrip::<u8>(&mut gen);
+/// roundtrip::<i64>(&mut gen);
+/// ```
+///
+/// With the `derive` feature, this trait can be derived for structs and
+/// enums. Every field is generated with [Weird::weird], and for enums the
+/// variant is picked uniformly.
+///
+/// ```ignore
+/// use weird_data::{Wdg, Weird};
+///
+/// #[derive(Weird)]
+/// struct Point {
+///     x: f32,
+///     y: i32,
+/// }
+///
+/// let point = Point::weird(&mut Wdg::with_seed(0));
+/// ```
+pub trait Weird: Sized {
+    /// Generate a random weird value.
+    fn weird(gen: &mut Wdg) -> Self;
+}
+
+impl Wdg {
+    /// Overwrite every element of `slice` with a weird value, generated with
+    /// [Weird::weird].
+    ///
+    /// This is useful for reusing a buffer instead of allocating a new one.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let mut buf = [0.0f32; 16];
+    /// gen.fill(&mut buf);
+    /// ```
+    pub fn fill<T: Weird>(&mut self, slice: &mut [T]) {
+        for x in slice {
+            *x = T::weird(self);
+        }
+    }
+
+    /// Generate a pair of weird values, with [Weird::weird].
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let (x, y) = gen.weird_pair::<f32, u8>();
+    /// ```
+    pub fn weird_pair<A: Weird, B: Weird>(&mut self) -> (A, B) {
+        (A::weird(self), B::weird(self))
+    }
+
+    /// Generate a triple of weird values, with [Weird::weird].
+    pub fn weird_triple<A: Weird, B: Weird, C: Weird>(&mut self) -> (A, B, C) {
+        (A::weird(self), B::weird(self), C::weird(self))
+    }
+
+    /// Generate a quadruple of weird values, with [Weird::weird].
+    pub fn weird_quad<A: Weird, B: Weird, C: Weird, D: Weird>(&mut self) -> (A, B, C, D) {
+        (
+            A::weird(self),
+            B::weird(self),
+            C::weird(self),
+            D::weird(self),
+        )
+    }
+
+    /// Generate a value of a user type with `f`.
+    ///
+    /// This is the same as calling `f(self)`, but reads better when chained
+    /// with other generators.
+    pub fn weird_with<T>(&mut self, f: impl FnOnce(&mut Wdg) -> T) -> T {
+        f(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Append `n` weird values, generated with [Weird::weird], to any
+    /// collection that implements [Extend], like a `Vec`, a `VecDeque` or a
+    /// `HashSet`.
+    ///
+    /// Unlike [Wdg::weird_vec], this reuses the allocation of an existing
+    /// collection. Collections like `Vec` can be extended with both values
+    /// and references, so the type of the values may need to be given
+    /// explicitly.
+    ///
+    /// ```
+    /// # use weird_data::Wdg;
+    /// let mut gen = Wdg::with_seed(0);
+    /// let mut v = vec![1u16, 2, 3];
+    /// gen.extend_weird::<u16, _>(&mut v, 10);
+    /// assert_eq!(v.len(), 13);
+    /// ```
+    pub fn extend_weird<T: Weird, C: Extend<T>>(&mut self, collection: &mut C, n: usize) {
+        collection.extend((0..n).map(|_| T::weird(self)));
+    }
+}
+
+macro_rules! weird {
+    ($($t:ident),+ $(,)?) => {
+        $(
+            impl Weird for $t {
+                #[doc = concat!("Same as [Wdg::", stringify!($t), "].")]
+                fn weird(gen: &mut Wdg) -> Self {
+                    gen.$t()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! weird_map {
+    ($($t:ident),+ $(,)?) => {
+        paste! {
+            impl Wdg {
+                $(
+                    #[doc = concat!("Generate a weird ", stringify!($t), " with [Wdg::", stringify!($t), "], and map it")]
+                    /// to a user type with `f`.
+                    ///
+                    /// ```
+                    /// # use weird_data::Wdg;
+                    /// struct Wrapper<T>(T);
+                    ///
+                    /// let mut gen = Wdg::with_seed(0);
+                    #[doc = concat!("let x = gen.weird_map_", stringify!($t), "(Wrapper);")]
+                    /// ```
+                    pub fn [<weird_map_ $t>]<T>(&mut self, f: impl FnOnce($t) -> T) -> T {
+                        f(self.$t())
+                    }
+                )+
+            }
+        }
+    };
+}
+
+macro_rules! weird_nonzero {
+    ($($t:ident),+ $(,)?) => {
+        $(
+            paste! {
+                impl Weird for [<NonZero $t:camel>] {
+                    #[doc = concat!("Same as [Wdg::nonzero_", stringify!($t), "].")]
+                    fn weird(gen: &mut Wdg) -> Self {
+                        gen.[<nonzero_ $t>]()
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl<T: Weird, const N: usize> Weird for [T; N] {
+    /// Generate every element with [Weird::weird], in order.
+    fn weird(gen: &mut Wdg) -> Self {
+        core::array::from_fn(|_| T::weird(gen))
+    }
+}
+
+weird!(f32, f64, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "half")]
+weird!(f16, bf16);
+
+weird_map!(f32, f64, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+weird_nonzero!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[doc(hidden)]
+pub mod __private {
+    //! Implementation details of the derive macro, not public API.
+
+    use crate::Wdg;
+
+    /// Uniformly pick the index of an enum variant.
+    pub fn variant_index(gen: &mut Wdg, count: usize) -> usize {
+        gen.0.usize(0..count)
+    }
+}
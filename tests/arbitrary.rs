@@ -0,0 +1,40 @@
+//! An example of a fuzz target driven by `cargo fuzz`, using the fuzzer's
+//! input to deterministically derive weird values.
+//!
+//! In a real fuzz target, the body of [fuzz_target] would be passed to the
+//! `fuzz_target!` macro of `libfuzzer-sys`.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::Unstructured;
+use weird_data::{FloatWeights, Wdg};
+
+/// Extract a `NAN`-heavy f32 from the fuzzer's input
+fn fuzz_target(data: &[u8]) -> arbitrary::Result<f32> {
+    let mut u = Unstructured::new(data);
+    let mut gen = Wdg::from_unstructured(&mut u)?;
+    Ok(gen.f32_weighted(&FloatWeights::new(1, 1, 7, 1)))
+}
+
+#[test]
+fn same_input_same_output() {
+    let data = [0x3e, 0x91, 0x07, 0xd4, 0x5b, 0xa8, 0x6c, 0xf2];
+    let a = fuzz_target(&data).unwrap();
+    let b = fuzz_target(&data).unwrap();
+    assert_eq!(a.to_bits(), b.to_bits());
+}
+
+#[test]
+fn nan_heavy() {
+    let mut nans = 0;
+    for i in 0..1000u64 {
+        let data = i.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes();
+        nans += fuzz_target(&data).unwrap().is_nan() as u32;
+    }
+    assert!(nans > 500, "{}", nans);
+}
+
+#[test]
+fn short_input() {
+    assert!(fuzz_target(&[]).is_ok());
+}
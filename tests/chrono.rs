@@ -0,0 +1,38 @@
+//! Generating dates that break calendar code.
+
+#![cfg(feature = "chrono")]
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use weird_data::Wdg;
+
+#[test]
+fn weird_naive_date() {
+    let mut gen = Wdg::with_seed(0x26_e1_9b_c5_48_0f_d7_a3);
+    let mut had_min = false;
+    let mut had_max = false;
+    let mut had_leap_day = false;
+    let mut had_new_year = false;
+    for _ in 0..10000 {
+        let date = gen.weird_naive_date();
+        had_min |= date == NaiveDate::MIN;
+        had_max |= date == NaiveDate::MAX;
+        had_leap_day |= date.month() == 2 && date.day() == 29;
+        had_new_year |= date.ordinal() == 1;
+    }
+    assert!(had_min && had_max && had_leap_day && had_new_year);
+}
+
+#[test]
+fn weird_datetime() {
+    let mut gen = Wdg::with_seed(0x91_4d_f8_06_ba_63_2c_e5);
+    let mut had_leap_second = false;
+    let mut had_max = false;
+    let mut had_dst = false;
+    for _ in 0..10000 {
+        let datetime = gen.weird_datetime();
+        had_leap_second |= datetime.nanosecond() >= 1_000_000_000;
+        had_max |= datetime == NaiveDateTime::MAX;
+        had_dst |= datetime.year() == 2021 && datetime.minute() == 30;
+    }
+    assert!(had_leap_second && had_max && had_dst);
+}
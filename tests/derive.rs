@@ -0,0 +1,80 @@
+#![cfg(feature = "derive")]
+
+use weird_data::{Wdg, Weird};
+
+#[derive(Weird)]
+struct Point {
+    x: f32,
+    y: i32,
+}
+
+#[derive(Weird)]
+struct Pair<T>(T, T);
+
+#[derive(Weird)]
+struct Unit;
+
+#[derive(Weird)]
+struct Packet {
+    header: [u8; 4],
+    samples: [[f32; 2]; 3],
+}
+
+#[allow(dead_code)]
+#[derive(Weird)]
+enum Shape {
+    Empty,
+    Circle { radius: f64 },
+    Line(u8, u8),
+}
+
+#[test]
+fn derive_struct() {
+    let mut gen = Wdg::with_seed(0x1e_c7_53_a9_04_d8_6b_f2);
+    let mut expected = gen.clone();
+    for _ in 0..1000 {
+        let point = Point::weird(&mut gen);
+        assert_eq!(point.x.to_bits(), expected.f32().to_bits());
+        assert_eq!(point.y, expected.i32());
+    }
+}
+
+#[test]
+fn derive_generic_tuple_struct() {
+    let mut gen = Wdg::with_seed(0x9b_21_e4_6d_f0_37_c8_5a);
+    let mut expected = gen.clone();
+    let Pair(a, b) = Pair::<u16>::weird(&mut gen);
+    assert_eq!(a, expected.u16());
+    assert_eq!(b, expected.u16());
+
+    let Unit = Unit::weird(&mut gen);
+}
+
+#[test]
+fn derive_array_fields() {
+    let mut gen = Wdg::with_seed(0x6f_d1_38_a0_c5_7b_e2_94);
+    let mut expected = gen.clone();
+    let packet = Packet::weird(&mut gen);
+    for byte in packet.header {
+        assert_eq!(byte, expected.u8());
+    }
+    for x in packet.samples.iter().flatten() {
+        assert_eq!(x.to_bits(), expected.f32().to_bits());
+    }
+}
+
+#[test]
+fn derive_enum() {
+    let mut gen = Wdg::with_seed(0x53_fa_0e_b7_28_c1_94_6d);
+    let mut had_empty = false;
+    let mut had_circle = false;
+    let mut had_line = false;
+    for _ in 0..1000 {
+        match Shape::weird(&mut gen) {
+            Shape::Empty => had_empty = true,
+            Shape::Circle { .. } => had_circle = true,
+            Shape::Line(..) => had_line = true,
+        }
+    }
+    assert!(had_empty && had_circle && had_line);
+}
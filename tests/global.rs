@@ -0,0 +1,42 @@
+//! The global functions, with `std`.
+
+#![cfg(feature = "std")]
+
+use weird_data::Wdg;
+
+#[test]
+fn integers_same_as_wdg() {
+    weird_data::seed(0xd4_1e_96_7b_c0_a3_58_2f);
+    let mut expected = Wdg::with_seed(0xd4_1e_96_7b_c0_a3_58_2f);
+    for _ in 0..100 {
+        assert_eq!(weird_data::special_i64(), expected.special_i64());
+        assert_eq!(weird_data::special_u8(), expected.special_u8());
+        assert_eq!(weird_data::u16(), expected.u16());
+        assert_eq!(weird_data::i128(), expected.i128());
+        assert_eq!(weird_data::nonzero_usize(), expected.nonzero_usize());
+    }
+    assert_eq!(weird_data::get_seed(), expected.get_seed());
+}
+
+#[test]
+fn preserves_signaling_nan_reentrant() {
+    // the closure draws from the global generator too, this must neither
+    // deadlock nor draw from a placeholder generator
+    weird_data::seed(0x38_f1_a6_0c_d9_57_e2_4b);
+    let mut expected = Wdg::with_seed(0x38_f1_a6_0c_d9_57_e2_4b);
+    let mut drawn = 0;
+    assert!(weird_data::preserves_signaling_nan_f32(|x| {
+        drawn = weird_data::u64();
+        x
+    }));
+    expected.signaling_nan_f32();
+    assert_eq!(drawn, expected.u64());
+
+    assert!(weird_data::preserves_signaling_nan_f64(|x| {
+        drawn = weird_data::u64();
+        x
+    }));
+    expected.signaling_nan_f64();
+    assert_eq!(drawn, expected.u64());
+    assert_eq!(weird_data::get_seed(), expected.get_seed());
+}
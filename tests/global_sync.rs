@@ -0,0 +1,16 @@
+#![cfg(feature = "global-sync")]
+
+use std::thread;
+
+use weird_data::Wdg;
+
+#[test]
+fn shared_across_threads() {
+    weird_data::seed(0x6c_e2_19_a5_f8_3d_70_b4);
+    let from_thread = thread::spawn(weird_data::u64).join().unwrap();
+    let from_main = weird_data::u64();
+
+    let mut expected = Wdg::with_seed(0x6c_e2_19_a5_f8_3d_70_b4);
+    assert_eq!(from_thread, expected.u64());
+    assert_eq!(from_main, expected.u64());
+}
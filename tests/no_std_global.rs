@@ -0,0 +1,18 @@
+//! The global functions without `std`, backed by a spinlock.
+//!
+//! Run with `cargo test --no-default-features --features no-std-global`.
+
+#![cfg(all(feature = "no-std-global", not(feature = "std")))]
+
+use weird_data::Wdg;
+
+#[test]
+fn same_as_wdg() {
+    weird_data::seed(0x38_c1_f6_0d_a9_52_e7_b4);
+    let mut expected = Wdg::with_seed(0x38_c1_f6_0d_a9_52_e7_b4);
+    for _ in 0..100 {
+        assert_eq!(weird_data::f32().to_bits(), expected.f32().to_bits());
+        assert_eq!(weird_data::u8(), expected.u8());
+    }
+    assert_eq!(weird_data::get_seed(), expected.get_seed());
+}
@@ -0,0 +1,48 @@
+//! Using weird values as the initial values of property tests.
+
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use weird_data::strategies::{weird_f32_strategy, weird_i32_strategy, weird_u8_strategy};
+
+proptest! {
+    #[test]
+    fn wrapping_add_zero(x in weird_i32_strategy()) {
+        prop_assert_eq!(x.wrapping_add(0), x);
+    }
+
+    #[test]
+    fn f32_roundtrip(x in weird_f32_strategy()) {
+        prop_assert_eq!(f32::from_bits(x.to_bits()).to_bits(), x.to_bits());
+    }
+}
+
+#[test]
+fn initial_values_are_weird() {
+    let mut runner = TestRunner::deterministic();
+    let mut had_nan = false;
+    let mut had_subnormal = false;
+    let mut had_max = false;
+    for _ in 0..1000 {
+        let x = weird_f32_strategy()
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        had_nan |= x.is_nan();
+        had_subnormal |= x.is_subnormal();
+
+        let x = weird_u8_strategy().new_tree(&mut runner).unwrap().current();
+        had_max |= x == u8::MAX;
+    }
+    assert!(had_nan && had_subnormal && had_max);
+}
+
+#[test]
+fn shrinks_towards_zero() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = weird_i32_strategy().new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), 0);
+}
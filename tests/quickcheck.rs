@@ -0,0 +1,28 @@
+//! Using weird values in quickcheck properties.
+
+#![cfg(feature = "quickcheck")]
+
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use weird_data::{WeirdF64, WeirdI64, WeirdU8};
+
+quickcheck! {
+    fn wrapping_neg_twice(x: WeirdI64) -> bool {
+        x.0.wrapping_neg().wrapping_neg() == x.0
+    }
+
+    fn to_bits_roundtrip(x: WeirdF64) -> bool {
+        f64::from_bits(x.0.to_bits()).to_bits() == x.0.to_bits()
+    }
+}
+
+#[test]
+fn values_are_weird() {
+    let mut g = Gen::new(100);
+    let mut had_nan = false;
+    let mut had_max = false;
+    for _ in 0..1000 {
+        had_nan |= WeirdF64::arbitrary(&mut g).0.is_nan();
+        had_max |= WeirdU8::arbitrary(&mut g).0 == u8::MAX;
+    }
+    assert!(had_nan && had_max);
+}
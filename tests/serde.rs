@@ -0,0 +1,25 @@
+//! Round-tripping a generator through JSON, like when it is stored alongside
+//! a fuzzing configuration.
+
+#![cfg(feature = "serde")]
+
+use weird_data::Wdg;
+
+#[test]
+fn json_round_trip() {
+    let mut gen = Wdg::with_seed(0xc3_5a_0e_97_f1_28_b4_6d);
+    gen.f32();
+
+    let json = serde_json::to_string(&gen).unwrap();
+    let mut restored: Wdg = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.get_seed(), gen.get_seed());
+    for _ in 0..100 {
+        assert_eq!(restored.f64().to_bits(), gen.f64().to_bits());
+    }
+}
+
+#[test]
+fn json_is_the_seed() {
+    let gen = Wdg::with_seed(42);
+    assert_eq!(serde_json::to_string(&gen).unwrap(), "42");
+}
@@ -0,0 +1,91 @@
+//! Derive macro for the `Weird` trait of the [weird-data] crate.
+//!
+//! Don't depend on this crate directly, enable the `derive` feature of
+//! `weird-data` instead.
+//!
+//! [weird-data]: https://crates.io/crates/weird-data
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam};
+
+/// Derive `Weird` for a struct or enum.
+///
+/// For a struct, every field is generated with `Weird::weird`. For an enum, a
+/// variant is picked uniformly, then its fields are generated in the same way.
+#[proc_macro_derive(Weird)]
+pub fn derive_weird(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(ty) = param {
+            ty.bounds.push(parse_quote!(::weird_data::Weird));
+        }
+    }
+
+    let body = match &input.data {
+        Data::Struct(data) => construct(quote!(Self), &data.fields),
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return syn::Error::new_spanned(
+                    &input.ident,
+                    "cannot derive `Weird` for an enum with no variants",
+                )
+                .to_compile_error()
+                .into();
+            }
+
+            let count = data.variants.len();
+            let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+                let ident = &variant.ident;
+                let construct = construct(quote!(Self::#ident), &variant.fields);
+                quote!(#i => #construct,)
+            });
+            quote! {
+                match ::weird_data::__private::variant_index(gen, #count) {
+                    #(#arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new_spanned(&input.ident, "cannot derive `Weird` for a union")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::weird_data::Weird for #ident #ty_generics #where_clause {
+            fn weird(gen: &mut ::weird_data::Wdg) -> Self {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Build an expression constructing `path` with every field set to a weird
+/// value.
+fn construct(path: TokenStream2, fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                quote!(#ident: ::weird_data::Weird::weird(gen))
+            });
+            quote!(#path { #(#fields),* })
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields
+                .unnamed
+                .iter()
+                .map(|_| quote!(::weird_data::Weird::weird(gen)));
+            quote!(#path(#(#fields),*))
+        }
+        Fields::Unit => path,
+    }
+}